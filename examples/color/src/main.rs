@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use iced::Border;
+use iced::advanced::widget::Id;
 use iced::{
-    Element, Fill, Length, Point, Rectangle, Task,
-    widget::Id,
+    Element, Fill, Length, Task,
     widget::{column, container, row, text},
 };
-use iced_drop::droppable;
+use iced_drop::drag_drop::{self, DragDrop};
+use iced_drop::widget::droppable::DropEffect;
+use iced_drop::{drop_zone, droppable};
 
 const HEADER_HEIGHT: f32 = 80.0;
 const COLORS_HEIGHT: f32 = 40.0;
@@ -24,26 +28,21 @@ fn main() -> iced::Result {
 
 #[derive(Debug, Clone)]
 enum Message {
-    DropColor(DColor, Point, Rectangle),
-    HandleZonesFound(DColor, Vec<(Id, Rectangle)>),
+    DragDrop(drag_drop::Message<Side, DColor>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Left,
+    Right,
 }
 
+#[derive(Default)]
 struct ColorDropper {
     left_color: DColor,
     right_color: DColor,
-    left: Id,
-    right: Id,
-}
-
-impl Default for ColorDropper {
-    fn default() -> Self {
-        Self {
-            left_color: DColor::Default,
-            right_color: DColor::Default,
-            left: Id::new("left"),
-            right: Id::new("right"),
-        }
-    }
+    hovered: Option<Side>,
+    drag_drop: DragDrop<Side, DColor>,
 }
 
 impl ColorDropper {
@@ -57,25 +56,41 @@ impl ColorDropper {
 
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::DropColor(color, point, _bounds) => {
-                return iced_drop::zones_on_point(
-                    move |zones| Message::HandleZonesFound(color, zones),
-                    point,
-                    None,
-                    None,
-                );
-            }
-            Message::HandleZonesFound(color, zones) => {
-                if let Some((zone, _)) = zones.first() {
-                    if *zone == self.left.clone() {
-                        self.left_color = color;
-                    } else {
-                        self.right_color = color;
+            Message::DragDrop(message) => {
+                // load the zones a color can be moved between with the keyboard
+                let targets = match &message {
+                    drag_drop::Message::Drag(color, _)
+                        if !self.drag_drop.is_dragging() =>
+                    {
+                        iced_drop::load_keyboard_targets(
+                            color.id(),
+                            Some(vec![Side::Left.id(), Side::Right.id()]),
+                            None,
+                        )
+                    }
+                    _ => Task::none(),
+                };
+                let (task, events) = self.drag_drop.update(message);
+                for event in events {
+                    match event {
+                        drag_drop::Event::DragEnter(side) => {
+                            self.hovered = Some(side);
+                        }
+                        drag_drop::Event::DragLeave(_) => self.hovered = None,
+                        drag_drop::Event::Dropped {
+                            payload: color,
+                            zone: Some(side),
+                            ..
+                        } => match side {
+                            Side::Left => self.left_color = color,
+                            Side::Right => self.right_color = color,
+                        },
+                        _ => (),
                     }
                 }
+                Task::batch([targets, task.map(Message::DragDrop)])
             }
         }
-        Task::none()
     }
 
     fn view(&self) -> Element<'_, Message> {
@@ -92,7 +107,18 @@ impl ColorDropper {
                     .width(Length::Fill)
                     .height(Length::Fixed(COLORS_HEIGHT)),
             )
-            .on_drop(move |point, rect| Message::DropColor(color, point, rect))
+            .id(color.id())
+            .payload(color)
+            // the colors stay in the palette when they're dropped
+            .effect(DropEffect::Copy)
+            .animation(Duration::from_millis(200))
+            .on_drag(|color, event| {
+                Message::DragDrop(drag_drop::Message::Drag(color, event))
+            })
+            .on_drop(|color, event| {
+                Message::DragDrop(drag_drop::Message::Drop(color, event))
+            })
+            .on_cancel(Message::DragDrop(drag_drop::Message::Cancel))
             .into()
         });
         let colors_holder =
@@ -104,8 +130,16 @@ impl ColorDropper {
             header,
             row![
                 colors_holder,
-                drop_zone(self.left_color, self.left.clone()),
-                drop_zone(self.right_color, self.right.clone())
+                color_zone(
+                    self.left_color,
+                    Side::Left,
+                    self.hovered == Some(Side::Left)
+                ),
+                color_zone(
+                    self.right_color,
+                    Side::Right,
+                    self.hovered == Some(Side::Right)
+                )
             ]
             .spacing(5)
         ]
@@ -114,17 +148,33 @@ impl ColorDropper {
     }
 }
 
-fn drop_zone<'a>(
+fn color_zone<'a>(
     color: DColor,
-    id: Id,
+    side: Side,
+    hovered: bool,
 ) -> iced::Element<'a, Message, iced::Theme, iced::Renderer> {
-    container(text(color.fun_fact()).size(20))
-        .style(move |_| color.style())
-        .id(id)
-        .width(Fill)
-        .height(Fill)
-        .center(Fill)
-        .into()
+    drop_zone(
+        side,
+        container(text(color.fun_fact()).size(20))
+            .style(move |_| {
+                let mut style = color.style();
+                if hovered {
+                    style.border.width = 5.0;
+                }
+                style
+            })
+            .width(Fill)
+            .height(Fill)
+            .center(Fill),
+    )
+    .id(side.id())
+    .into()
+}
+
+impl Side {
+    fn id(&self) -> Id {
+        Id::from(format!("{:?}", self))
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -163,6 +213,10 @@ impl DColor {
         DColor::Pink,
     ];
 
+    fn id(&self) -> Id {
+        Id::from(self.to_string())
+    }
+
     fn color(&self) -> iced::Color {
         match self {
             DColor::Default => iced::Color::from_rgb8(245, 245, 245),
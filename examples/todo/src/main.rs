@@ -1,20 +1,15 @@
 use std::time::Instant;
 
-use highlight::{
-    should_update_droppable, zone_update, Highlight, Highlightable, ZoneUpdate,
-};
 use iced::{
     advanced::widget::Id, widget::operation::focus, widget::{column, container, text}, Element, Length,
-    Point,
-    Rectangle,
     Task,
 };
+use iced_drop::collision::RectIntersection;
+use iced_drop::drag_drop::{self, DragDrop};
 use iced_drop::widget::droppable::State as DroppableState;
-use operation::swap_modify_states;
+use iced_drop::widget::sortable::Transfer;
 use tree::{List, Slot, Todo, TreeData, TreeElement, TreeLocation};
 
-mod highlight;
-mod operation;
 mod theme;
 mod tree;
 
@@ -40,27 +35,19 @@ enum Message {
     WriteTodo(TreeLocation),
 
     // Drag/drop to-dos
-    DragTodo(TreeLocation, Rectangle),
-    HandleTodoZones(Vec<(Id, Rectangle)>),
-    #[allow(dead_code)]
-    DropTodo(TreeLocation, Point, Rectangle),
-    TodoDropCanceled,
+    MoveTodo(TreeLocation, usize, usize),
+    TransferTodo(Transfer),
+    DeleteTodo(TreeLocation, usize),
 
     // Drag/drop lists
-    #[allow(dead_code)]
-    DragList(TreeLocation, Point, Rectangle),
-    HandleListZones(Vec<(Id, Rectangle)>),
-    #[allow(dead_code)]
-    DropList(TreeLocation, Point, Rectangle),
-    ListDropCanceled,
+    DragDrop(drag_drop::Message<TreeLocation, TreeLocation>),
 }
 
 struct TodoBoard {
     tree: TreeData,
     clicked: (TreeLocation, Instant),
     editing: Option<TreeLocation>,
-    todos_highlight: Highlight,
-    lists_highlight: Highlight,
+    drag_drop: DragDrop<TreeLocation, TreeLocation>,
 }
 
 impl Default for TodoBoard {
@@ -73,8 +60,8 @@ impl Default for TodoBoard {
             ]),
             clicked: (tree::NULL_TODO_LOC, Instant::now()),
             editing: None,
-            todos_highlight: Highlight::default(),
-            lists_highlight: Highlight::default(),
+            // the slots don't move while a list is dragged, so they're cached
+            drag_drop: DragDrop::new().collision(RectIntersection).cached(true),
         }
     }
 }
@@ -129,110 +116,32 @@ impl TodoBoard {
             Message::StopEditingTodo => {
                 self.stop_editing();
             }
-            // To-do drag/drop
-            Message::DragTodo(t_loc, t_bounds) => {
-                let new_highlight = highlight::dragged(
-                    &self.todos_highlight,
-                    t_loc,
-                    t_bounds,
-                );
-                if should_update_droppable(
-                    &self.todos_highlight,
-                    &new_highlight,
-                    &t_loc,
-                )
-                    && let Some(todo) = self.tree.todo_mut(&t_loc) {
-                        todo.set_highlight(true)
-                    }
-                self.todos_highlight = new_highlight;
-                return iced_drop::find_zones(
-                    Message::HandleTodoZones,
-                    move |zone_bounds| zone_bounds.intersects(&t_bounds),
-                    Some(self.tree.todo_options(&t_loc)),
-                    None,
-                );
-            }
-            Message::HandleTodoZones(zones) => {
-                let new_highlight = highlight::zones_found(
-                    &self.todos_highlight,
-                    &map_zones(&self.tree, zones),
-                );
-                zone_update(&self.todos_highlight, &new_highlight).update(
-                    &mut self.tree,
-                    &self.todos_highlight,
-                    &new_highlight,
-                );
-                self.todos_highlight = new_highlight;
-            }
-            Message::DropTodo(t_loc, _, _) => {
-                if let Some(h_loc) = &self.todos_highlight.hovered {
-                    match h_loc.element() {
-                        TreeElement::List => {
-                            todo_dropped_on_list(&mut self.tree, &t_loc, h_loc)
-                        }
-                        TreeElement::Todo(_) => {
-                            todo_dropped_on_todo(&mut self.tree, &t_loc, h_loc)
-                        }
-                        _ => (),
-                    }
-                } else {
-                    self.tree.list_mut(&t_loc).remove(&t_loc);
-                }
-                self.todos_highlight = highlight::dropped();
+            Message::MoveTodo(l_loc, from, to) => {
+                self.stop_editing();
+                self.tree.list_mut(&l_loc).move_todo(from, to);
             }
-
-            // List drag/drop
-            Message::DragList(l_loc, _, l_bounds) => {
-                let new_highlight = highlight::dragged(
-                    &self.lists_highlight,
-                    l_loc,
-                    l_bounds,
-                );
-                if should_update_droppable(
-                    &self.lists_highlight,
-                    &new_highlight,
-                    &l_loc,
-                ) {
-                    self.tree.list_mut(&l_loc).set_highlight(false);
+            Message::TransferTodo(transfer) => {
+                self.stop_editing();
+                let Some(from) = self.tree.list_by_todos(&transfer.from_list)
+                else {
+                    return Task::none();
+                };
+                let todo = from.remove(transfer.from_index);
+                if let Some(to) = self.tree.list_by_todos(&transfer.to_list) {
+                    to.insert(todo, transfer.to_index);
                 }
-                self.lists_highlight = new_highlight;
-                return iced_drop::find_zones(
-                    Message::HandleListZones,
-                    move |zone_bounds| zone_bounds.intersects(&l_bounds),
-                    Some(self.tree.list_options()),
-                    None,
-                );
             }
-            Message::HandleListZones(zones) => {
-                let new_info = highlight::zones_found(
-                    &self.lists_highlight,
-                    &map_zones(&self.tree, zones),
-                );
-                let highlight_update =
-                    zone_update(&self.lists_highlight, &new_info);
-                highlight_update.update(
-                    &mut self.tree,
-                    &self.lists_highlight,
-                    &new_info,
-                );
-                self.lists_highlight = new_info;
-
-                if highlight_update == ZoneUpdate::Replace
-                    && let Some(d_loc) = &self.lists_highlight.dragging
-                        && let Some(h_loc) = &self.lists_highlight.hovered {
-                            return move_list_to_zone(
-                                &mut self.tree,
-                                &d_loc.0,
-                                h_loc,
-                            );
-                        }
+            Message::DeleteTodo(l_loc, index) => {
+                self.stop_editing();
+                self.tree.list_mut(&l_loc).remove(index);
             }
-            Message::DropList(l_loc, _, _) => {
-                self.tree.list_mut(&l_loc).set_highlight(false);
-                if let Some(s_loc) = &self.lists_highlight.hovered {
-                    self.tree.slot_mut(s_loc.slot()).set_highlight(false);
+            Message::DragDrop(message) => {
+                let (task, events) = self.drag_drop.update(message);
+                let mut tasks = vec![task.map(Message::DragDrop)];
+                for event in events {
+                    tasks.push(self.handle_drag_event(event));
                 }
-                self.todos_highlight = highlight::dropped();
+                return Task::batch(tasks);
             }
             Message::UpdateTodoWriter(l_loc, new_str) => {
                 self.stop_editing();
@@ -252,31 +161,32 @@ impl TodoBoard {
                 self.tree.list_mut(&l_loc).push(todo);
                 return focus(id);
             }
-            Message::TodoDropCanceled => {
-                if let Some(d_loc) = &self.todos_highlight.dragging
-                    && let Some(todo) = self.tree.todo_mut(&d_loc.0) {
-                        todo.set_highlight(false);
-                        highlight::set_hovered(
-                            &mut self.tree,
-                            &self.todos_highlight,
-                            false,
-                        );
-                    }
-                self.todos_highlight = highlight::dropped();
-            }
-            Message::ListDropCanceled => {
-                if let Some(d_loc) = &self.lists_highlight.dragging {
-                    self.tree.list_mut(&d_loc.0).set_highlight(false);
-                    self.tree.slot_mut(d_loc.0.slot()).set_highlight(false);
-                }
-                self.lists_highlight = highlight::dropped();
-            }
         }
         Task::none()
     }
 }
 
 impl TodoBoard {
+    fn handle_drag_event(
+        &mut self,
+        event: drag_drop::Event<TreeLocation, TreeLocation>,
+    ) -> Task<Message> {
+        // only lists are dragged with the controller, to-dos are sortable
+        let drag_drop::Event::Hovering { zone: Some(h_loc) } = event else {
+            return Task::none();
+        };
+
+        // lists are moved as soon as they're dragged over another slot, the
+        // placeholder of the dragged list then shows where it will be dropped
+        if let Some(d_loc) = self.drag_drop.payload()
+            && *d_loc.element() == TreeElement::List
+            && d_loc.slot() != h_loc.slot()
+        {
+            return move_list_to_zone(&mut self.tree, d_loc, &h_loc);
+        }
+        Task::none()
+    }
+
     fn stop_editing(&mut self) {
         if let Some(loc) = self.editing
             && let Some(todo) = self.tree.todo_mut(&loc) {
@@ -286,66 +196,6 @@ impl TodoBoard {
     }
 }
 
-fn map_zones(
-    tree: &TreeData,
-    zones: Vec<(Id, Rectangle)>,
-) -> Vec<(TreeLocation, Rectangle)> {
-    zones
-        .into_iter()
-        .filter_map(|(id, rect)| {
-            tree.find(&id).map(|loc| (loc, rect))
-        })
-        .collect()
-}
-
-fn todo_dropped_on_list(
-    tree: &mut TreeData,
-    d_loc: &TreeLocation,
-    h_loc: &TreeLocation,
-) {
-    if let Some(todo) = tree.todo_mut(d_loc) {
-        todo.set_highlight(false);
-        let todo = {
-            let list = tree.list_mut(h_loc);
-            list.set_highlight(false);
-            if d_loc.slot() == h_loc.slot() {
-                return;
-            }
-            if let Some(todo) = tree.list_mut(d_loc).remove(d_loc) {
-                todo
-            } else {
-                return;
-            }
-        };
-        tree.list_mut(h_loc).push(todo);
-    }
-}
-
-fn todo_dropped_on_todo(
-    tree: &mut TreeData,
-    d_loc: &TreeLocation,
-    h_loc: &TreeLocation,
-) {
-    if let Some(d_todo) = tree.todo_mut(d_loc) {
-        d_todo.set_highlight(false);
-        let h_todo = tree.todo_mut(h_loc);
-        if let Some(todo) = h_todo {
-            todo.set_highlight(false);
-        } else {
-            return;
-        }
-    }
-
-    if d_loc.slot() != h_loc.slot() {
-        if let TreeElement::Todo(i) = h_loc.element() {
-            let todo = tree.list_mut(d_loc).remove(d_loc).unwrap();
-            tree.list_mut(h_loc).insert(todo, *i);
-        }
-    } else {
-        tree.list_mut(d_loc).move_todo(d_loc, h_loc);
-    }
-}
-
 fn move_list_to_zone(
     tree: &mut TreeData,
     d_loc: &TreeLocation,
@@ -354,9 +204,9 @@ fn move_list_to_zone(
     let l1 = tree.list_mut(d_loc).id();
     let l2 = tree.list_mut(h_loc).id();
     tree.swap_lists(d_loc, h_loc);
-    swap_modify_states(
+    iced_drop::swap_states(
         l1,
         l2,
-        |_old: &DroppableState, new: &DroppableState| *new,
+        |_old: &DroppableState, new: &DroppableState| new.clone(),
     )
 }
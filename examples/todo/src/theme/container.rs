@@ -1,17 +1,8 @@
 use iced::widget::container::Style;
-use iced::{Border, Theme, color};
+use iced::{Border, Color, Theme, color};
 
-pub fn active_slot(_theme: &Theme) -> Style {
-    Style {
-        background: Some(color!(202, 233, 255).into()),
-        border: Border {
-            color: color!(202, 233, 255),
-            radius: 10.0.into(),
-            width: 5.0,
-        },
-        ..Default::default()
-    }
-}
+/// The color of the outline left where a list is dragged from
+pub const PLACEHOLDER: Color = color!(202, 233, 255);
 
 pub fn title(_theme: &Theme) -> Style {
     Style {
@@ -31,18 +22,6 @@ pub fn list(_theme: &Theme) -> Style {
     }
 }
 
-pub fn active_list(_theme: &Theme) -> Style {
-    Style {
-        background: Some(color!(172, 193, 138).into()),
-        border: Border {
-            color: color!(202, 233, 255),
-            radius: 10.0.into(),
-            width: 5.0,
-        },
-        ..Default::default()
-    }
-}
-
 pub fn todo(_theme: &Theme) -> Style {
     Style {
         background: Some(color!(218, 254, 183).into()),
@@ -54,18 +33,6 @@ pub fn todo(_theme: &Theme) -> Style {
     }
 }
 
-pub fn active_todo(_theme: &Theme) -> Style {
-    Style {
-        background: Some(color!(218, 254, 183).into()),
-        border: Border {
-            color: color!(202, 233, 255),
-            radius: 10.0.into(),
-            width: 5.0,
-        },
-        ..Default::default()
-    }
-}
-
 pub fn background(_theme: &Theme) -> Style {
     Style {
         background: Some(color!(96, 91, 86).into()),
@@ -3,12 +3,16 @@ use std::sync::atomic::AtomicUsize;
 use iced::advanced::widget::Id;
 use iced::widget::tooltip;
 use iced::{
-    Center, Element, Length, Size, alignment,
-    widget::{button, column, container, row, space, text, text_input},
+    Center, Element, Length, alignment,
+    widget::{
+        button, column, container, mouse_area, row, space, text, text_input,
+    },
 };
-use iced_drop::droppable;
+use iced_drop::drag_drop;
+use iced_drop::widget::droppable::Placeholder;
+use iced_drop::{drop_zone, droppable, sortable, transfers};
 
-use crate::{Message, highlight::Highlightable, theme};
+use crate::{Message, theme};
 
 pub const NULL_TODO_LOC: TreeLocation = TreeLocation {
     slot: 0,
@@ -58,35 +62,23 @@ impl TreeData {
     }
     /// Convert the tree into an element that iced can render
     pub fn view(&self) -> Element<'_, Message> {
-        let children =
-            self.slots.iter().enumerate().map(|(i, slot)| slot.view(i));
-        row(children)
-            .spacing(10.0)
-            .padding(20.0)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
-    }
-
-    pub fn find(&self, id: &Id) -> Option<TreeLocation> {
-        for (i, slot) in self.slots.iter().enumerate() {
-            if slot.id == *id {
-                return Some(TreeLocation::new(i, TreeElement::Slot));
-            }
-            if slot.list.id == *id {
-                return Some(TreeLocation::new(i, TreeElement::List));
-            }
-            for (j, list) in slot.list.todos.iter().enumerate() {
-                if list.id == *id {
-                    return Some(TreeLocation::new(i, TreeElement::Todo(j)));
-                }
-            }
-        }
-        None
-    }
-
-    pub fn slot_mut(&mut self, index: usize) -> &mut Slot {
-        self.slots.get_mut(index).unwrap()
+        // to-dos dropped anywhere on a list, like its name, aren't deleted
+        let lists: Vec<Id> =
+            self.slots.iter().map(|slot| slot.list.id()).collect();
+        let children = self
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| slot.view(i, &lists));
+        // the to-dos are dragged between the lists through the transfers
+        transfers(
+            row(children)
+                .spacing(10.0)
+                .padding(20.0)
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .into()
     }
 
     pub fn list_mut(&mut self, location: &TreeLocation) -> &mut List {
@@ -98,13 +90,12 @@ impl TreeData {
         }
     }
 
-    pub fn todo(&self, location: &TreeLocation) -> Option<&Todo> {
-        let i = location.slot;
-        match location.element {
-            TreeElement::Slot => None,
-            TreeElement::List => None,
-            TreeElement::Todo(j) => Some(&self.slots[i].list.todos[j]),
-        }
+    /// Find the list whose to-dos are in the sortable with the given id
+    pub fn list_by_todos(&mut self, id: &Id) -> Option<&mut List> {
+        self.slots
+            .iter_mut()
+            .map(|slot| &mut slot.list)
+            .find(|list| list.todos_id == *id)
     }
 
     pub fn todo_mut(&mut self, location: &TreeLocation) -> Option<&mut Todo> {
@@ -124,76 +115,30 @@ impl TreeData {
 
         std::mem::swap(&mut s1.list, &mut s2.list);
     }
-
-    /// Returns the widget Id of all the widgets which a item can be dropped on
-    pub fn todo_options(&self, t_loc: &TreeLocation) -> Vec<Id> {
-        let todo_id = if let Some(todo) = self.todo(t_loc) {
-            todo.id.clone()
-        } else {
-            return vec![];
-        };
-        self.slots
-            .iter()
-            .flat_map(|slot| {
-                slot.list.todos.iter().filter_map(|todo| {
-                    if todo.id != todo_id {
-                        Some(todo.id.clone())
-                    } else {
-                        None
-                    }
-                })
-            })
-            .chain(self.slots.iter().map(|slot| slot.list.id.clone()))
-            .collect()
-    }
-
-    /// Returns the widget Id of all the widgets wich a list can be dropped on
-    pub fn list_options(&self) -> Vec<Id> {
-        self.slots.iter().map(|slot| slot.id.clone()).collect()
-    }
 }
 
-static NEXT_SLOT: AtomicUsize = AtomicUsize::new(0);
-
 /// Some slot that a list can be dragged into
 pub struct Slot {
-    id: Id,
     list: List,
-    c_id: Id,
-    highlight: bool,
-}
-
-impl Highlightable for Slot {
-    fn set_highlight(&mut self, highlight: bool) {
-        self.highlight = highlight;
-    }
 }
 
 impl Slot {
     /// Create a new slot with a list
     pub fn new(list: List) -> Self {
-        let id = NEXT_SLOT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        let c_id = Id::from(format!("slot_{}", id));
         Self {
-            id: c_id.clone(),
-            c_id,
             list,
-            highlight: false,
         }
     }
 
     /// Convert the slot into an element that iced can render
-    fn view(&self, index: usize) -> Element<'_, Message> {
-        container(self.list.view(index))
-            .id(self.c_id.clone())
-            .style(if self.highlight {
-                theme::container::active_slot
-            } else {
-                container::transparent
-            })
+    fn view(&self, index: usize, lists: &[Id]) -> Element<'_, Message> {
+        let content = container(self.list.view(index, lists))
             .width(Length::Fill)
             .height(Length::Fill)
-            .padding(3.5)
+            .padding(3.5);
+        // only lists can be dropped on slots
+        drop_zone(TreeLocation::new(index, TreeElement::Slot), content)
+            .accept(|d_loc: &TreeLocation| d_loc.element == TreeElement::List)
             .into()
     }
 }
@@ -218,15 +163,9 @@ static NEXT_LIST: AtomicUsize = AtomicUsize::new(0);
 pub struct List {
     pub todo_adder: ElementAdder,
     id: Id,
+    todos_id: Id,
     title: String,
     todos: Vec<Todo>,
-    highlight: bool,
-}
-
-impl Highlightable for List {
-    fn set_highlight(&mut self, highlight: bool) {
-        self.highlight = highlight;
-    }
 }
 
 impl List {
@@ -235,19 +174,15 @@ impl List {
         let id = NEXT_LIST.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         Self {
             id: Id::from(format!("list_{}", id)),
+            todos_id: Id::from(format!("todos_{}", id)),
             title: title.to_string(),
             todo_adder: ElementAdder::new(id),
             todos,
-            highlight: false,
         }
     }
 
-    pub fn remove(&mut self, loc: &TreeLocation) -> Option<Todo> {
-        if let TreeElement::Todo(i) = loc.element() {
-            Some(self.todos.remove(*i))
-        } else {
-            None
-        }
+    pub fn remove(&mut self, index: usize) -> Todo {
+        self.todos.remove(index)
     }
 
     pub fn push(&mut self, todo: Todo) {
@@ -258,14 +193,9 @@ impl List {
         self.todos.insert(index, todo);
     }
 
-    pub fn move_todo(&mut self, from: &TreeLocation, to: &TreeLocation) {
-        if let (TreeElement::Todo(i), TreeElement::Todo(j)) =
-            (from.element(), to.element())
-        {
-            let insert_index = if i < j { j - 1 } else { *j };
-            let todo = self.todos.remove(*i);
-            self.todos.insert(insert_index, todo);
-        }
+    pub fn move_todo(&mut self, from: usize, to: usize) {
+        let todo = self.todos.remove(from);
+        self.todos.insert(to, todo);
     }
 
     pub fn id(&self) -> Id {
@@ -273,35 +203,44 @@ impl List {
     }
 
     /// Convert the list into an element that iced can render
-    fn view(&self, slot_index: usize) -> Element<'_, Message> {
+    fn view(&self, slot_index: usize, lists: &[Id]) -> Element<'_, Message> {
         let name = text(self.title.clone())
             .size(20)
             .style(theme::text::list_name);
         let location = TreeLocation::new(slot_index, TreeElement::List);
-        let todos = column(self.todos.iter().enumerate().map(|(i, todo)| {
+        // to-dos can be reordered, dragged into any other list, or dropped outside of the lists
+        // to delete them
+        let todos = sortable(self.todos.iter().enumerate(), |(i, todo)| {
             todo.view(TreeLocation::new(slot_index, TreeElement::Todo(i)))
-        }))
+        })
+        .id(self.todos_id.clone())
+        .group("todos")
         .spacing(10.0)
         .width(Length::Fill)
-        .height(Length::Shrink)
-        .push(self.adder(location));
+        .height(Length::Fill)
+        .on_reorder(move |from, to| Message::MoveTodo(location, from, to))
+        .on_transfer(Message::TransferTodo)
+        .on_drop_outside(move |i| Message::DeleteTodo(location, i))
+        .inside(lists.iter().cloned());
+        let todos = column![todos, self.adder(location)].spacing(10.0);
         let content = container(column![name, todos].spacing(20.0))
             .width(Length::Fill)
-            .height(Length::Shrink)
+            .height(Length::Fill)
             .padding(10.0)
-            .style(if self.highlight {
-                theme::container::active_list
-            } else {
-                theme::container::list
-            });
-        droppable(content)
+            .style(theme::container::list);
+        let list = droppable(content)
             .id(self.id.clone())
             .on_click(Message::StopEditingTodo)
-            .on_drop(move |p, r| Message::DropList(location, p, r))
-            .on_drag(move |p, r| Message::DragList(location, p, r))
-            .on_cancel(Message::ListDropCanceled)
-            .drag_hide(true)
-            .into()
+            .payload(location)
+            .on_drop(|l_loc, event| {
+                drag_message(drag_drop::Message::Drop(l_loc, event))
+            })
+            .on_drag(|l_loc, event| {
+                drag_message(drag_drop::Message::Drag(l_loc, event))
+            })
+            .on_cancel(drag_message(drag_drop::Message::Cancel))
+            .placeholder(Placeholder::Outline(theme::container::PLACEHOLDER));
+        list.into()
     }
 
     fn adder(&self, location: TreeLocation) -> Element<'_, Message> {
@@ -335,15 +274,7 @@ static NEXT_TODO: AtomicUsize = AtomicUsize::new(0);
 pub struct Todo {
     pub content: String,
     pub editing: bool,
-    id: Id,
     t_id: Id,
-    highlight: bool,
-}
-
-impl Highlightable for Todo {
-    fn set_highlight(&mut self, highlight: bool) {
-        self.highlight = highlight;
-    }
 }
 
 impl Todo {
@@ -351,10 +282,8 @@ impl Todo {
     pub fn new(content: &str) -> Self {
         let id = NEXT_TODO.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         Self {
-            id: Id::from(format!("todo_{}", id)),
             t_id: Id::from(format!("todo_input_{}", id)),
             content: content.to_string(),
-            highlight: false,
             editing: false,
         }
     }
@@ -370,21 +299,12 @@ impl Todo {
             .padding(10.0)
             .width(Length::Fill)
             .height(Length::Shrink)
-            .style(if self.highlight {
-                theme::container::active_todo
-            } else {
-                theme::container::todo
-            });
+            .style(theme::container::todo);
 
         if !self.editing {
-            droppable(content)
-                .id(self.id.clone())
-                .on_click(Message::EditTodo(location, self.t_id.clone()))
-                .on_drop(move |p, r| Message::DropTodo(location, p, r))
-                .on_drag(move |_p, r| Message::DragTodo(location, r))
-                .on_cancel(Message::TodoDropCanceled)
-                .drag_hide(true)
-                .drag_size(Size::ZERO)
+            // the sortable captures presses, so clicks are handled on release
+            mouse_area(content)
+                .on_release(Message::EditTodo(location, self.t_id.clone()))
                 .into()
         } else {
             text_input("", &self.content)
@@ -397,3 +317,9 @@ impl Todo {
         }
     }
 }
+
+fn drag_message(
+    message: drag_drop::Message<TreeLocation, TreeLocation>,
+) -> Message {
+    Message::DragDrop(message)
+}
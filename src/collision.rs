@@ -0,0 +1,200 @@
+//! Strategies that decide which drop zones collide with a dragged [`Droppable`].
+//!
+//! [`Droppable`]: crate::widget::droppable::Droppable
+use iced_core::{Point, Rectangle, Size};
+
+/// Decides whether a drop zone collides with a dragged [`Droppable`], and ranks it.
+///
+/// Closures with the signature of [`Collision::collide`] are strategies too.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub trait Collision {
+    /// Returns the rank of the `zone` if it collides with the dragged bounds, or `None` if it
+    /// doesn't.
+    ///
+    /// `pointer` is the cursor position and `dragged` the bounds of the dragged [`Droppable`].
+    /// Zones with a lower rank are better targets.
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    fn collide(
+        &self,
+        pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32>;
+
+    /// Returns the region a zone has to reach to collide, or `None` if any zone may collide.
+    ///
+    /// An indexed [`Snapshot`] only tests the zones reaching this region.
+    ///
+    /// [`Snapshot`]: crate::snapshot::Snapshot
+    fn region(&self, _pointer: Point, _dragged: Rectangle) -> Option<Rectangle> {
+        None
+    }
+}
+
+impl<F> Collision for F
+where
+    F: Fn(Point, Rectangle, Rectangle) -> Option<f32>,
+{
+    fn collide(
+        &self,
+        pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        self(pointer, dragged, zone)
+    }
+}
+
+/// Zones containing the cursor collide. Smaller zones rank first, so the innermost of nested
+/// zones is the best target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointerWithin;
+
+impl Collision for PointerWithin {
+    fn collide(
+        &self,
+        pointer: Point,
+        _dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        zone.contains(pointer).then(|| zone.area())
+    }
+
+    fn region(&self, pointer: Point, _dragged: Rectangle) -> Option<Rectangle> {
+        Some(Rectangle::new(pointer, Size::ZERO))
+    }
+}
+
+/// Zones intersecting the dragged bounds collide. Zones are ranked by the ratio of the
+/// intersection to the union of both rectangles, so zones of a similar size and position rank
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RectIntersection;
+
+impl Collision for RectIntersection {
+    fn collide(
+        &self,
+        _pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        let overlap = zone.intersection(&dragged)?.area();
+        let union = zone.area() + dragged.area() - overlap;
+
+        (union > 0.0).then(|| -overlap / union)
+    }
+
+    fn region(&self, _pointer: Point, dragged: Rectangle) -> Option<Rectangle> {
+        Some(dragged)
+    }
+}
+
+/// Zones intersecting the dragged bounds collide. Zones with the largest intersection area rank
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LargestOverlap;
+
+impl Collision for LargestOverlap {
+    fn collide(
+        &self,
+        _pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        zone.intersection(&dragged).map(|overlap| -overlap.area())
+    }
+
+    fn region(&self, _pointer: Point, dragged: Rectangle) -> Option<Rectangle> {
+        Some(dragged)
+    }
+}
+
+/// Every zone collides. Zones whose center is closest to the center of the dragged bounds rank
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClosestCenter;
+
+impl Collision for ClosestCenter {
+    fn collide(
+        &self,
+        _pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        Some(zone.center().distance(dragged.center()))
+    }
+}
+
+/// Every zone collides. Zones are ranked by the sum of the distances between their corners and
+/// the matching corners of the dragged bounds, which favors zones of a similar size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClosestCorners;
+
+impl Collision for ClosestCorners {
+    fn collide(
+        &self,
+        _pointer: Point,
+        dragged: Rectangle,
+        zone: Rectangle,
+    ) -> Option<f32> {
+        Some(
+            corners(&zone)
+                .iter()
+                .zip(corners(&dragged))
+                .map(|(a, b)| a.distance(b))
+                .sum(),
+        )
+    }
+}
+
+fn corners(bounds: &Rectangle) -> [Point; 4] {
+    [
+        Point::new(bounds.x, bounds.y),
+        Point::new(bounds.x + bounds.width, bounds.y),
+        Point::new(bounds.x, bounds.y + bounds.height),
+        Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+    ]
+}
+
+/// Ranks the given zones with a [`Collision`] strategy.
+///
+/// Zones that don't collide are removed, and the remaining zones are sorted from the best to the
+/// worst target. Zones with an equal rank keep their order.
+pub fn rank<K>(
+    collision: &(impl Collision + ?Sized),
+    pointer: Point,
+    dragged: Rectangle,
+    zones: Vec<(K, Rectangle)>,
+) -> Vec<(K, Rectangle)> {
+    let mut ranked: Vec<(f32, (K, Rectangle))> = zones
+        .into_iter()
+        .filter_map(|zone| {
+            collision
+                .collide(pointer, dragged, zone.1)
+                .map(|rank| (rank, zone))
+        })
+        .collect();
+    ranked.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    ranked.into_iter().map(|(_, zone)| zone).collect()
+}
+
+/// Ranks zones given as `(key, bounds, visible)` on their visible bounds, like [`rank`], and
+/// returns them with their full bounds.
+pub(crate) fn rank_visible<K>(
+    collision: &(impl Collision + ?Sized),
+    pointer: Point,
+    dragged: Rectangle,
+    zones: impl IntoIterator<Item = (K, Rectangle, Rectangle)>,
+) -> Vec<(K, Rectangle)> {
+    let zones = zones
+        .into_iter()
+        .map(|(key, bounds, visible)| ((key, bounds), visible))
+        .collect();
+
+    rank(collision, pointer, dragged, zones)
+        .into_iter()
+        .map(|(zone, _)| zone)
+        .collect()
+}
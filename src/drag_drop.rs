@@ -0,0 +1,524 @@
+//! A controller that owns the state of a drag and drop session.
+//!
+//! Without it, every drop needs two messages: one that starts a zone query, and one that handles
+//! the zones found. [`DragDrop`] issues the zone queries itself and resolves them into
+//! [`Event`]s. It also keeps track of the zones under the dragged payload between drag moves, so
+//! applications get enter, over and leave events for every zone.
+use std::fmt;
+use std::sync::Arc;
+
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::Id;
+use iced_core::{Point, Rectangle};
+use iced_runtime::Task;
+use iced_runtime::task::widget as operate;
+
+use crate::collision::{Collision, PointerWithin};
+use crate::snapshot::Snapshot;
+use crate::widget::droppable::{DragEvent, DropEffect};
+use crate::widget::operation::drop;
+
+/// The messages handled by a [`DragDrop`] controller.
+///
+/// [`Drag`], [`Drop`] and [`Cancel`] are meant to be produced by the `on_drag`, `on_drop` and
+/// `on_cancel` callbacks of a [`Droppable`]. [`ZonesFound`] and [`ZonesCached`] are produced by
+/// the tasks the controller issues.
+///
+/// [`Drag`]: Message::Drag
+/// [`Drop`]: Message::Drop
+/// [`Cancel`]: Message::Cancel
+/// [`ZonesFound`]: Message::ZonesFound
+/// [`ZonesCached`]: Message::ZonesCached
+/// [`Droppable`]: crate::widget::droppable::Droppable
+#[derive(Debug, Clone)]
+pub enum Message<K, T> {
+    /// A [`Droppable`] carrying the payload is being dragged.
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    Drag(T, DragEvent),
+    /// A [`Droppable`] carrying the payload was dropped.
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    Drop(T, DragEvent),
+    /// The drag was cancelled.
+    Cancel,
+    /// The zones under a dragged or dropped payload were found.
+    ZonesFound(Found<K, T>),
+    /// A snapshot of the zones was taken for the drag.
+    ZonesCached(Cached<K>),
+}
+
+/// The result of a zone query issued by a [`DragDrop`] controller.
+#[derive(Debug, Clone)]
+pub struct Found<K, T> {
+    session: usize,
+    zones: Vec<(K, Rectangle)>,
+    point: Point,
+    effect: DropEffect,
+    dropped: Option<T>,
+    droppable: Option<Id>,
+}
+
+/// A snapshot of the zones taken by a [`DragDrop`] controller.
+#[derive(Debug, Clone)]
+pub struct Cached<K> {
+    session: usize,
+    snapshot: Snapshot<K>,
+}
+
+/// A resolved drag and drop event produced by a [`DragDrop`] controller.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<K, T> {
+    /// The dragged payload entered a zone.
+    DragEnter(K),
+    /// The dragged payload moved over a zone, with the cursor at the given position.
+    DragOver(K, Point),
+    /// The dragged payload left a zone, or was dropped or cancelled while over it.
+    DragLeave(K),
+    /// The best target under the dragged payload changed.
+    Hovering {
+        /// The best target now under the payload, if any.
+        zone: Option<K>,
+    },
+    /// The payload was dropped.
+    Dropped {
+        /// The payload of the dropped [`Droppable`].
+        ///
+        /// [`Droppable`]: crate::widget::droppable::Droppable
+        payload: T,
+        /// The zone the payload was dropped on, if any.
+        zone: Option<K>,
+        /// The cursor position at the drop.
+        point: Point,
+        /// The [`DropEffect`] chosen for the drop.
+        effect: DropEffect,
+    },
+    /// The drag was cancelled.
+    Cancelled {
+        /// The payload being dragged, if the controller received any drag message.
+        payload: Option<T>,
+    },
+}
+
+/// The [`Task`] a [`DragDrop`] controller needs to run, and the resolved [`Event`]s.
+pub type Update<K, T> = (Task<Message<K, T>>, Vec<Event<K, T>>);
+
+/// Owns the state of a drag and drop session between [`Droppable`]s and [`DropZone`]s with
+/// keys of type `K`, where the dragged [`Droppable`]s carry a payload of type `T`.
+///
+/// The zones colliding with the dragged payload are decided by a [`Collision`] strategy, which
+/// is [`PointerWithin`] by default.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+#[derive(Clone)]
+pub struct DragDrop<K, T> {
+    dragging: Option<(T, DragEvent)>,
+    hovered: Vec<K>,
+    collision: Arc<dyn Collision + Send + Sync>,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+    session: usize,
+    auto_scroll: Option<AutoScroll>,
+    cached: bool,
+    index: bool,
+    snapshot: Option<Snapshot<K>>,
+}
+
+/// How a [`DragDrop`] controller scrolls the scrollables under the cursor.
+#[derive(Debug, Clone, Copy)]
+struct AutoScroll {
+    margin: f32,
+    speed: f32,
+    last: Option<Instant>,
+}
+
+impl<K, T> Default for DragDrop<K, T> {
+    fn default() -> Self {
+        Self {
+            dragging: None,
+            hovered: vec![],
+            collision: Arc::new(PointerWithin),
+            options: None,
+            depth: None,
+            session: 0,
+            auto_scroll: None,
+            cached: false,
+            index: false,
+            snapshot: None,
+        }
+    }
+}
+
+impl<K, T> fmt::Debug for DragDrop<K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragDrop")
+            .field("dragging", &self.dragging)
+            .field("hovered", &self.hovered)
+            .field("options", &self.options)
+            .field("depth", &self.depth)
+            .field("session", &self.session)
+            .field("auto_scroll", &self.auto_scroll)
+            .field("cached", &self.cached)
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, T> DragDrop<K, T>
+where
+    K: Clone + PartialEq + Send + 'static,
+    T: Clone + Send + 'static,
+{
+    /// Creates a new [`DragDrop`] controller.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`Collision`] strategy deciding which zones collide with the dragged payload.
+    pub fn collision(
+        mut self,
+        collision: impl Collision + Send + Sync + 'static,
+    ) -> Self {
+        self.collision = Arc::new(collision);
+        self
+    }
+
+    /// Sets the keys of the zones considered by the controller.
+    ///
+    /// If `options` is `None`, all zones with a key of type `K` will be considered.
+    pub fn options(mut self, options: Option<Vec<K>>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets how deep into nested zones the controller should look.
+    ///
+    /// If `depth` is `None`, nested zones will be fully explored.
+    pub fn depth(mut self, depth: Option<usize>) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Scrolls the scrollables under the cursor while it's within `margin` of their edges.
+    ///
+    /// The closer the cursor is to an edge, the faster the scrollable is scrolled, up to `speed`
+    /// pixels per second. Scrolling goes on while the cursor rests near an edge if the dragged
+    /// [`Droppable`] has an Id.
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    pub fn auto_scroll(mut self, margin: f32, speed: f32) -> Self {
+        self.auto_scroll = Some(AutoScroll {
+            margin,
+            speed,
+            last: None,
+        });
+        self
+    }
+
+    /// Sets whether the zones are cached during a drag.
+    ///
+    /// When they are, a [`Snapshot`] of the zones is taken when a drag starts, and the drag is
+    /// hit-tested against it instead of walking the widget tree on every move. Call [`refresh`]
+    /// when the layout changes during a drag. The snapshot is refreshed after auto-scrolling.
+    ///
+    /// [`refresh`]: DragDrop::refresh
+    pub fn cached(mut self, cached: bool) -> Self {
+        self.cached = cached;
+        self
+    }
+
+    /// Caches the zones during a drag like [`cached`], and sorts them into a grid, so only the
+    /// zones near the dragged payload are hit-tested.
+    ///
+    /// This pays off with thousands of zones, as long as the [`Collision`] strategy has a
+    /// [`region`].
+    ///
+    /// [`cached`]: DragDrop::cached
+    /// [`region`]: Collision::region
+    pub fn index(mut self) -> Self {
+        self.cached = true;
+        self.index = true;
+        self
+    }
+
+    /// Takes a new [`Snapshot`] of the zones, if they are [`cached`] and a payload is being
+    /// dragged.
+    ///
+    /// [`cached`]: DragDrop::cached
+    pub fn refresh(&self) -> Task<Message<K, T>> {
+        match &self.dragging {
+            Some((payload, _)) if self.cached => self.cache(payload.clone()),
+            _ => Task::none(),
+        }
+    }
+
+    /// Returns the payload being dragged, if any.
+    pub fn payload(&self) -> Option<&T> {
+        self.dragging.as_ref().map(|(payload, _)| payload)
+    }
+
+    /// Returns the [`DropEffect`] of the drag, if any.
+    pub fn effect(&self) -> Option<DropEffect> {
+        self.dragging.as_ref().map(|(_, event)| event.effect)
+    }
+
+    /// Returns the best target under the dragged payload, if any.
+    pub fn hovered(&self) -> Option<&K> {
+        self.hovered.first()
+    }
+
+    /// Returns all the zones colliding with the dragged payload, from the best to the worst
+    /// target.
+    pub fn hovered_zones(&self) -> &[K] {
+        &self.hovered
+    }
+
+    /// Returns whether a payload is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+
+    /// Processes a [`Message`], returning the [`Task`] the controller needs to run and the
+    /// resolved [`Event`]s.
+    pub fn update(&mut self, message: Message<K, T>) -> Update<K, T> {
+        match message {
+            Message::Drag(payload, event) => {
+                let mut tasks = vec![self.scroll(payload.clone(), &event)];
+                if self.cached && self.dragging.is_none() {
+                    tasks.push(self.cache(payload.clone()));
+                }
+
+                // until the snapshot is taken, the widget tree is walked
+                let events = match self.hit(payload.clone(), &event, false) {
+                    Some(found) => self.resolve(found),
+                    None => {
+                        tasks.push(self.find(payload.clone(), &event, false));
+                        vec![]
+                    }
+                };
+                self.dragging = Some((payload, event));
+                (Task::batch(tasks), events)
+            }
+            Message::Drop(payload, event) => {
+                let found = self.hit(payload.clone(), &event, true);
+                self.dragging = None;
+                self.snapshot = None;
+                self.stop_scrolling();
+                self.session += 1;
+
+                match found {
+                    Some(found) => (settle(&found), self.resolve(found)),
+                    None => (self.find(payload, &event, true), vec![]),
+                }
+            }
+            Message::Cancel => {
+                let payload = self.dragging.take().map(|(payload, _)| payload);
+                self.snapshot = None;
+                self.session += 1;
+                self.stop_scrolling();
+                let mut events = self.leave_all();
+                events.push(Event::Cancelled { payload });
+                (Task::none(), events)
+            }
+            Message::ZonesFound(found) => (settle(&found), self.resolve(found)),
+            Message::ZonesCached(cached) => {
+                // a snapshot taken for a drag that has since ended is stale
+                if cached.session == self.session && self.dragging.is_some() {
+                    self.snapshot = Some(cached.snapshot);
+                }
+                (Task::none(), vec![])
+            }
+        }
+    }
+
+    /// Hit-tests the snapshot of the zones, if one was taken.
+    fn hit(
+        &self,
+        payload: T,
+        event: &DragEvent,
+        dropped: bool,
+    ) -> Option<Found<K, T>> {
+        let snapshot = self.snapshot.as_ref()?;
+
+        Some(Found {
+            session: self.session,
+            zones: snapshot.hit(
+                self.collision.as_ref(),
+                event.point,
+                event.bounds,
+                Some(event.effect),
+            ),
+            point: event.point,
+            effect: event.effect,
+            dropped: dropped.then_some(payload),
+            droppable: event.id.clone(),
+        })
+    }
+
+    fn cache(&self, payload: T) -> Task<Message<K, T>> {
+        let session = self.session;
+        let index = self.index;
+        operate(drop::snapshot_zones(payload, self.options.clone(), self.depth))
+            .map(move |snapshot| {
+                let snapshot =
+                    if index { snapshot.index() } else { snapshot };
+                Message::ZonesCached(Cached { session, snapshot })
+            })
+    }
+
+    fn find(
+        &self,
+        payload: T,
+        event: &DragEvent,
+        dropped: bool,
+    ) -> Task<Message<K, T>> {
+        let session = self.session;
+        let point = event.point;
+        let effect = event.effect;
+        let dropped = dropped.then(|| payload.clone());
+        let droppable = event.id.clone();
+        operate(drop::ranked_zones(
+            self.collision.clone(),
+            point,
+            event.bounds,
+            Some(Box::new(payload)),
+            Some(effect),
+            self.options.clone(),
+            self.depth,
+        ))
+        .map(move |zones| {
+            Message::ZonesFound(Found {
+                session,
+                zones: zones
+                    .into_iter()
+                    .map(|zone| (zone.key, zone.bounds))
+                    .collect(),
+                point,
+                effect,
+                dropped: dropped.clone(),
+                droppable: droppable.clone(),
+            })
+        })
+    }
+
+    fn scroll(
+        &mut self,
+        payload: T,
+        event: &DragEvent,
+    ) -> Task<Message<K, T>> {
+        if self.auto_scroll.is_none() {
+            return Task::none();
+        }
+        // scrolling moves the zones, so the snapshot has to be taken again
+        let mut refresh = self.cached.then(|| self.cache(payload));
+
+        let Some(auto_scroll) = &mut self.auto_scroll else {
+            return Task::none();
+        };
+
+        // the scrollables are scrolled by the time elapsed since the last drag message, so the
+        // speed doesn't depend on how often they're sent
+        let now = Instant::now();
+        let elapsed = auto_scroll
+            .last
+            .replace(now)
+            .map(|last| now.duration_since(last))
+            .unwrap_or_default()
+            .min(Duration::from_millis(50));
+
+        operate(drop::auto_scroll(
+            event.id.clone(),
+            event.point,
+            auto_scroll.margin,
+            auto_scroll.speed * elapsed.as_secs_f32(),
+        ))
+        .then(move |scrolled| match refresh.take() {
+            Some(refresh) if scrolled => refresh,
+            _ => Task::none(),
+        })
+    }
+
+    fn stop_scrolling(&mut self) {
+        if let Some(auto_scroll) = &mut self.auto_scroll {
+            auto_scroll.last = None;
+        }
+    }
+
+    fn resolve(&mut self, found: Found<K, T>) -> Vec<Event<K, T>> {
+        // zones are ranked from the best to the worst target
+        let zone = found.zones.first().map(|(key, _)| key.clone());
+
+        if let Some(payload) = found.dropped {
+            let mut events = self.leave_all();
+            events.push(Event::Dropped {
+                payload,
+                zone,
+                point: found.point,
+                effect: found.effect,
+            });
+            return events;
+        }
+
+        // zones found for a drag that has since ended are stale
+        if found.session != self.session || self.dragging.is_none() {
+            return vec![];
+        }
+
+        let zones: Vec<K> =
+            found.zones.into_iter().map(|(key, _)| key).collect();
+        let mut events: Vec<Event<K, T>> = self
+            .hovered
+            .iter()
+            .filter(|key| !zones.contains(key))
+            .map(|key| Event::DragLeave(key.clone()))
+            .collect();
+        events.extend(
+            zones
+                .iter()
+                .filter(|key| !self.hovered.contains(key))
+                .map(|key| Event::DragEnter(key.clone())),
+        );
+        events.extend(
+            zones
+                .iter()
+                .map(|key| Event::DragOver(key.clone(), found.point)),
+        );
+
+        if zone.as_ref() != self.hovered.first() {
+            events.push(Event::Hovering { zone });
+        }
+        self.hovered = zones;
+
+        events
+    }
+
+    fn leave_all(&mut self) -> Vec<Event<K, T>> {
+        let mut events: Vec<Event<K, T>> =
+            self.hovered.drain(..).map(Event::DragLeave).collect();
+        if !events.is_empty() {
+            events.push(Event::Hovering { zone: None });
+        }
+        events
+    }
+}
+
+/// Glides a dropped [`Droppable`] into the zone it was dropped on.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+fn settle<K, T>(found: &Found<K, T>) -> Task<Message<K, T>>
+where
+    K: Send + 'static,
+    T: Send + 'static,
+{
+    match (&found.droppable, found.zones.first()) {
+        (Some(droppable), Some((_, bounds))) if found.dropped.is_some() => {
+            operate(drop::settle(droppable.clone(), *bounds)).discard()
+        }
+        _ => Task::none(),
+    }
+}
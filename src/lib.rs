@@ -1,7 +1,14 @@
+pub mod collision;
+pub mod snapshot;
 pub mod widget;
+#[cfg(feature = "helpers")]
+pub mod drag_drop;
 
 use iced_core::{renderer, Element};
 use widget::droppable::*;
+use widget::drop_zone::*;
+use widget::sortable::*;
+use widget::keyed;
 
 #[cfg(feature = "helpers")]
 use iced_core::Point;
@@ -10,18 +17,34 @@ use iced_core::widget::Id;
 #[cfg(feature = "helpers")]
 use iced_widget::graphics::futures::MaybeSend;
 #[cfg(feature = "helpers")]
+use crate::widget::operation::drag;
+#[cfg(feature = "helpers")]
 use crate::widget::operation::drop;
 #[cfg(feature = "helpers")]
+use crate::widget::operation::drop::{TypedZone, Zone};
+#[cfg(feature = "helpers")]
+use crate::widget::operation::state;
+#[cfg(feature = "helpers")]
 use iced_core::Rectangle;
 #[cfg(feature = "helpers")]
 use iced_runtime::task::widget as operate;
 #[cfg(feature = "helpers")]
 use iced_runtime::Task;
 
+#[cfg(feature = "helpers")]
+pub use drag_drop::DragDrop;
+
+#[cfg(not(feature = "helpers"))]
+use widget::operation::{drag, drop, state};
 #[cfg(not(feature = "helpers"))]
-use widget::operation::drop;
+pub use drag::{cancel_drag, drag_status, reset_drag, start_drag};
 #[cfg(not(feature = "helpers"))]
-pub use drop::find_zones;
+pub use state::{move_states, swap_states, transfer_states};
+#[cfg(not(feature = "helpers"))]
+pub use drop::{
+    auto_scroll, find_accepting_zones, find_ranked_zones, find_typed_zones,
+    find_zone_paths, find_zones, load_keyboard_targets, settle, snapshot_zones,
+};
 
 pub fn droppable<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -33,6 +56,49 @@ where
     Droppable::new(content)
 }
 
+pub fn drop_zone<'a, K, Message, Theme, Renderer>(
+    key: K,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, K, Message, Theme, Renderer>
+where
+    K: Clone + PartialEq + Send + 'static,
+    Renderer: renderer::Renderer,
+{
+    DropZone::new(key, content)
+}
+
+pub fn sortable<'a, T, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = T>,
+    view: impl Fn(T) -> Element<'a, Message, Theme, Renderer>,
+) -> Sortable<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    Sortable::new(items.into_iter().map(view))
+}
+
+pub fn transfers<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Transfers<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    Transfers::new(content)
+}
+
+pub fn keyed_column<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<
+        Item = (Key, Element<'a, Message, Theme, Renderer>),
+    >,
+) -> keyed::Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    keyed::Column::new(children)
+}
+
 #[cfg(feature = "helpers")]
 pub fn zones_on_point<T, MF>(
     msg: MF,
@@ -42,7 +108,7 @@ pub fn zones_on_point<T, MF>(
 ) -> Task<T>
 where
     T: Send + 'static,
-    MF: Fn(Vec<(Id, Rectangle)>) -> T + MaybeSend + Sync + Clone + 'static,
+    MF: Fn(Vec<Zone>) -> T + MaybeSend + Sync + Clone + 'static,
 {
     operate(drop::find_zones(
         move |bounds| bounds.contains(point),
@@ -61,7 +127,7 @@ pub fn find_zones<Message, MF, F>(
 ) -> Task<Message>
 where
     Message: Send + 'static,
-    MF: Fn(Vec<(Id, Rectangle)>) -> Message
+    MF: Fn(Vec<Zone>) -> Message
     + MaybeSend
     + Sync
     + Clone
@@ -70,3 +136,251 @@ where
 {
     operate(drop::find_zones(filter, options, depth)).map(msg)
 }
+
+#[cfg(feature = "helpers")]
+pub fn typed_zones_on_point<T, K, MF>(
+    msg: MF,
+    point: Point,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<T>
+where
+    T: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    MF: Fn(Vec<TypedZone<K>>) -> T + MaybeSend + Sync + Clone + 'static,
+{
+    operate(drop::find_typed_zones(
+        move |bounds| bounds.contains(point),
+        options,
+        depth,
+    ))
+        .map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn find_typed_zones<Message, K, MF, F>(
+    msg: MF,
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    MF: Fn(Vec<TypedZone<K>>) -> Message
+    + MaybeSend
+    + Sync
+    + Clone
+    + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    operate(drop::find_typed_zones(filter, options, depth)).map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn find_zone_paths<Message, K, MF, F>(
+    msg: MF,
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    MF: Fn(Vec<TypedZone<K>>) -> Message
+    + MaybeSend
+    + Sync
+    + Clone
+    + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    operate(drop::find_zone_paths(filter, options, depth)).map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn find_accepting_zones<Message, K, P, MF, F>(
+    msg: MF,
+    payload: P,
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    P: Send + 'static,
+    MF: Fn(Vec<TypedZone<K>>) -> Message
+    + MaybeSend
+    + Sync
+    + Clone
+    + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    operate(drop::find_accepting_zones(payload, filter, options, depth))
+        .map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn find_ranked_zones<Message, K, C, MF>(
+    msg: MF,
+    collision: C,
+    pointer: Point,
+    dragged: Rectangle,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    C: collision::Collision + Send + Sync + 'static,
+    MF: Fn(Vec<TypedZone<K>>) -> Message
+    + MaybeSend
+    + Sync
+    + Clone
+    + 'static,
+{
+    operate(drop::find_ranked_zones(
+        collision, pointer, dragged, options, depth,
+    ))
+    .map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn snapshot_zones<Message, K, P, MF>(
+    msg: MF,
+    payload: P,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    K: Clone + PartialEq + Send + 'static,
+    P: Send + 'static,
+    MF: Fn(snapshot::Snapshot<K>) -> Message
+    + MaybeSend
+    + Sync
+    + Clone
+    + 'static,
+{
+    operate(drop::snapshot_zones(payload, options, depth)).map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn load_keyboard_targets<Message>(
+    droppable: Id,
+    options: Option<Vec<Id>>,
+    depth: Option<usize>,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drop::load_keyboard_targets(droppable, options, depth)).discard()
+}
+
+#[cfg(feature = "helpers")]
+pub fn settle<Message>(droppable: Id, bounds: Rectangle) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drop::settle(droppable, bounds)).discard()
+}
+
+#[cfg(feature = "helpers")]
+pub fn auto_scroll<Message>(
+    droppable: Option<Id>,
+    point: Point,
+    margin: f32,
+    step: f32,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drop::auto_scroll(droppable, point, margin, step)).discard()
+}
+
+#[cfg(feature = "helpers")]
+pub fn start_drag<Message>(droppable: Id, point: Point) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drag::start_drag(droppable, point)).discard().chain(relayout())
+}
+
+#[cfg(feature = "helpers")]
+pub fn cancel_drag<Message>(droppable: Id) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drag::cancel_drag(droppable)).discard().chain(relayout())
+}
+
+#[cfg(feature = "helpers")]
+pub fn reset_drag<Message>(droppable: Id) -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    operate(drag::reset_drag(droppable)).discard().chain(relayout())
+}
+
+/// Lays out and redraws the windows, so the droppables changed by a drag operation are shown
+/// right away and publish their messages without waiting for the next input event.
+#[cfg(feature = "helpers")]
+fn relayout<Message>() -> Task<Message>
+where
+    Message: Send + 'static,
+{
+    iced_runtime::task::effect(iced_runtime::Action::Window(
+        iced_runtime::window::Action::RelayoutAll,
+    ))
+}
+
+#[cfg(feature = "helpers")]
+pub fn drag_status<Message, MF>(msg: MF, droppable: Id) -> Task<Message>
+where
+    Message: Send + 'static,
+    MF: Fn(Option<(Action, Status)>) -> Message
+        + MaybeSend
+        + Sync
+        + Clone
+        + 'static,
+{
+    operate(drag::drag_status(droppable)).map(msg)
+}
+
+#[cfg(feature = "helpers")]
+pub fn transfer_states<Message, S, F>(
+    transfers: Vec<(Id, Id)>,
+    modify: F,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    operate(state::transfer_states(transfers, modify)).discard()
+}
+
+#[cfg(feature = "helpers")]
+pub fn swap_states<Message, S, F>(a: Id, b: Id, modify: F) -> Task<Message>
+where
+    Message: Send + 'static,
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    operate(state::swap_states(a, b, modify)).discard()
+}
+
+#[cfg(feature = "helpers")]
+pub fn move_states<Message, S, F>(
+    ids: Vec<Id>,
+    from: usize,
+    to: usize,
+    modify: F,
+) -> Task<Message>
+where
+    Message: Send + 'static,
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    operate(state::move_states(ids, from, to, modify)).discard()
+}
@@ -0,0 +1,412 @@
+//! Snapshots of the drop zones, hit-tested without walking the widget tree.
+//!
+//! Finding the zones under a dragged [`Droppable`] walks the whole widget tree, which adds up
+//! when it's done on every cursor move in a large interface. A [`Snapshot`] is taken once with
+//! the [`snapshot_zones`] operation, and is then hit-tested locally until the layout changes.
+//!
+//! With thousands of zones, even testing every zone of the snapshot adds up. An indexed
+//! snapshot sorts its zones into a uniform grid, so a query only tests the zones in the cells it
+//! reaches, however many zones there are.
+//!
+//! [`Droppable`]: crate::widget::droppable::Droppable
+//! [`snapshot_zones`]: crate::widget::operation::drop::snapshot_zones
+use std::collections::HashMap;
+use std::ops::Range;
+
+use iced_core::{Point, Rectangle, Size};
+
+use crate::collision::{Collision, rank_visible};
+use crate::widget::droppable::DropEffect;
+
+/// The drop zones with a key of type `K` found when the snapshot was taken.
+#[derive(Debug, Clone)]
+pub struct Snapshot<K> {
+    zones: Vec<CachedZone<K>>,
+    grid: Option<Grid>,
+}
+
+/// A drop zone in a [`Snapshot`].
+#[derive(Debug, Clone)]
+pub(crate) struct CachedZone<K> {
+    pub(crate) key: K,
+    pub(crate) bounds: Rectangle,
+    pub(crate) visible: Rectangle,
+    pub(crate) effects: Option<Vec<DropEffect>>,
+}
+
+impl<K> Snapshot<K> {
+    pub(crate) fn new(zones: Vec<CachedZone<K>>) -> Self {
+        Self { zones, grid: None }
+    }
+
+    /// Creates a [`Snapshot`] of zones with the given keys and bounds, for applications that
+    /// know where their zones are without walking the widget tree.
+    pub fn from_bounds(
+        zones: impl IntoIterator<Item = (K, Rectangle)>,
+    ) -> Self {
+        Self::new(
+            zones
+                .into_iter()
+                .map(|(key, bounds)| CachedZone {
+                    key,
+                    bounds,
+                    visible: bounds,
+                    effects: None,
+                })
+                .collect(),
+        )
+    }
+
+    /// Sorts the zones into a uniform grid, so queries only test the zones near them.
+    ///
+    /// The cells are the size of the median zone, so a few zones much larger or further away than
+    /// the others don't make them any larger. Zones reaching a lot of cells, or whose bounds aren't
+    /// finite, are tested by every query.
+    pub fn index(mut self) -> Self {
+        self.grid = Grid::new(&self.zones);
+        self
+    }
+
+    /// Returns whether the zones are sorted into a grid.
+    pub fn is_indexed(&self) -> bool {
+        self.grid.is_some()
+    }
+
+    /// Returns the number of zones in the [`Snapshot`].
+    pub fn len(&self) -> usize {
+        self.zones.len()
+    }
+
+    /// Returns whether the [`Snapshot`] has no zones.
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+
+    /// Returns the keys and bounds of the zones in the [`Snapshot`].
+    pub fn zones(&self) -> impl Iterator<Item = (&K, Rectangle)> {
+        self.zones.iter().map(|zone| (&zone.key, zone.bounds))
+    }
+
+    /// Returns the zones that may reach the region, or every zone if there's no region or grid.
+    fn candidates(
+        &self,
+        region: Option<Rectangle>,
+    ) -> impl Iterator<Item = &CachedZone<K>> {
+        let indices: Box<dyn Iterator<Item = usize>> =
+            match (&self.grid, region) {
+                (Some(grid), Some(region)) => {
+                    Box::new(grid.query(region).into_iter())
+                }
+                _ => Box::new(0..self.zones.len()),
+            };
+
+        indices.map(|index| &self.zones[index])
+    }
+}
+
+impl<K: Clone> Snapshot<K> {
+    /// Returns the zones whose visible bounds contain the point.
+    pub fn at(&self, point: Point) -> Vec<(K, Rectangle)> {
+        self.candidates(Some(Rectangle::new(point, Size::ZERO)))
+            .filter(|zone| zone.visible.contains(point))
+            .map(|zone| (zone.key.clone(), zone.bounds))
+            .collect()
+    }
+
+    /// Returns the zones whose visible bounds intersect the region.
+    pub fn intersecting(&self, region: Rectangle) -> Vec<(K, Rectangle)> {
+        self.candidates(Some(region))
+            .filter(|zone| zone.visible.intersects(&region))
+            .map(|zone| (zone.key.clone(), zone.bounds))
+            .collect()
+    }
+
+    /// Returns the zones colliding with a dragged [`Droppable`], ranked from the best to the
+    /// worst target, like the [`find_ranked_zones`] operation.
+    ///
+    /// If an `effect` is given, only the zones accepting it are considered. An indexed
+    /// [`Snapshot`] only tests the zones reaching the [`Collision::region`].
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    /// [`find_ranked_zones`]: crate::widget::operation::drop::find_ranked_zones
+    pub fn hit(
+        &self,
+        collision: &(impl Collision + ?Sized),
+        pointer: Point,
+        dragged: Rectangle,
+        effect: Option<DropEffect>,
+    ) -> Vec<(K, Rectangle)> {
+        let zones = self
+            .candidates(collision.region(pointer, dragged))
+            .filter(|zone| {
+                effect.is_none_or(|effect| {
+                    zone.effects
+                        .as_ref()
+                        .is_none_or(|effects| effects.contains(&effect))
+                }) && collision
+                    .collide(pointer, dragged, zone.visible)
+                    .is_some()
+            })
+            .map(|zone| (zone.key.clone(), zone.bounds, zone.visible));
+
+        rank_visible(collision, pointer, dragged, zones)
+    }
+}
+
+/// The most cells a zone is sorted into, past which it's tested by every query instead.
+const CELLS_PER_ZONE: i64 = 16;
+
+/// A uniform grid holding the indices of the zones reaching each of its cells.
+///
+/// Only the cells some zone reaches are kept, so zones far apart don't need the empty cells
+/// between them.
+#[derive(Debug, Clone)]
+struct Grid {
+    cell_size: f32,
+    /// The zones reaching each cell, by column and row
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    /// The zones whose bounds aren't finite, or that reach too many cells, which every query
+    /// reaches.
+    unbounded: Vec<usize>,
+}
+
+impl Grid {
+    /// Sorts the zones into a grid of cells the size of the median zone, if there are any.
+    fn new<K>(zones: &[CachedZone<K>]) -> Option<Self> {
+        if zones.is_empty() {
+            return None;
+        }
+
+        let indices = 0..zones.len();
+        let (bounded, mut unbounded): (Vec<usize>, Vec<usize>) =
+            indices.partition(|&index| is_finite(zones[index].visible));
+
+        // a few zones much larger or further than the others don't change the median
+        let mut sizes: Vec<f32> = bounded
+            .iter()
+            .map(|&index| {
+                let size = zones[index].visible.size();
+                size.width.max(size.height)
+            })
+            .collect();
+        let middle = sizes.len() / 2;
+        let cell_size = if sizes.is_empty() {
+            1.0
+        } else {
+            *sizes.select_nth_unstable_by(middle, f32::total_cmp).1
+        };
+
+        let mut grid = Grid {
+            cell_size: cell_size.max(1.0),
+            cells: HashMap::new(),
+            unbounded: vec![],
+        };
+
+        for index in bounded {
+            let (columns, rows) = grid.span(zones[index].visible);
+            if cells(&columns, &rows) > CELLS_PER_ZONE {
+                unbounded.push(index);
+                continue;
+            }
+
+            for row in rows {
+                for column in columns.clone() {
+                    grid.cells.entry((column, row)).or_default().push(index);
+                }
+            }
+        }
+        grid.unbounded = unbounded;
+
+        Some(grid)
+    }
+
+    /// Returns the columns and rows of the cells the region reaches.
+    fn span(&self, region: Rectangle) -> (Range<i64>, Range<i64>) {
+        let cell = |offset: f32| (offset / self.cell_size).floor() as i64;
+
+        (
+            cell(region.x)..cell(region.x + region.width).saturating_add(1),
+            cell(region.y)..cell(region.y + region.height).saturating_add(1),
+        )
+    }
+
+    /// Returns the indices of the zones in the cells the region reaches, in ascending order.
+    fn query(&self, region: Rectangle) -> Vec<usize> {
+        let (columns, rows) = self.span(region);
+
+        // a large region has more cells than the grid keeps
+        let large = cells(&columns, &rows) > self.cells.len() as i64;
+        let cells: Box<dyn Iterator<Item = &Vec<usize>>> = if large {
+            Box::new(self.cells.iter().filter_map(|((column, row), cell)| {
+                (columns.contains(column) && rows.contains(row)).then_some(cell)
+            }))
+        } else {
+            Box::new(rows.flat_map(|row| {
+                columns
+                    .clone()
+                    .filter_map(move |column| self.cells.get(&(column, row)))
+            }))
+        };
+
+        let mut indices: Vec<usize> = cells
+            .flatten()
+            .copied()
+            .chain(self.unbounded.iter().copied())
+            .collect();
+
+        // zones spanning several cells are found once, in the order they were found in
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+/// Returns the number of cells in the given columns and rows.
+fn cells(columns: &Range<i64>, rows: &Range<i64>) -> i64 {
+    let count = |range: &Range<i64>| range.end.saturating_sub(range.start);
+    count(columns).saturating_mul(count(rows))
+}
+
+/// Returns whether the position and size of the bounds are finite.
+fn is_finite(bounds: Rectangle) -> bool {
+    bounds.x.is_finite()
+        && bounds.y.is_finite()
+        && bounds.width.is_finite()
+        && bounds.height.is_finite()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::{PointerWithin, RectIntersection};
+
+    const ZONE_SIZE: f32 = 20.0;
+    const QUERIES: usize = 2_000;
+
+    /// A square board of `side * side` zones, laid out like an inventory grid.
+    fn board(side: usize) -> Vec<(usize, Rectangle)> {
+        (0..side * side)
+            .map(|index| {
+                let position = Point::new(
+                    (index % side) as f32 * ZONE_SIZE,
+                    (index / side) as f32 * ZONE_SIZE,
+                );
+                (
+                    index,
+                    Rectangle::new(position, Size::new(ZONE_SIZE, ZONE_SIZE)),
+                )
+            })
+            .collect()
+    }
+
+    /// Points spread over a board, so no part of it is favored.
+    fn points(side: usize) -> Vec<Point> {
+        let extent = side as f32 * ZONE_SIZE;
+        (0..QUERIES)
+            .map(|i| {
+                let t = i as f32 / QUERIES as f32;
+                Point::new(
+                    (t * 7919.0 % 1.0) * extent,
+                    (t * 104_729.0 % 1.0) * extent,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the most zones tested by a query anywhere on a board, cell corners included.
+    fn most_tested(snapshot: &Snapshot<usize>, side: usize) -> usize {
+        (0..=side * 2)
+            .flat_map(|x| (0..=side * 2).map(move |y| (x, y)))
+            .map(|(x, y)| {
+                let pointer = Point::new(
+                    x as f32 * ZONE_SIZE / 2.0,
+                    y as f32 * ZONE_SIZE / 2.0,
+                );
+                let dragged = Rectangle::new(pointer, Size::new(15.0, 15.0));
+                snapshot
+                    .candidates(PointerWithin.region(pointer, dragged))
+                    .count()
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn indexed_query_cost_stays_flat() {
+        // 100 zones to 90,000 zones
+        for side in [10, 30, 100, 300] {
+            let snapshot = Snapshot::from_bounds(board(side)).index();
+            let tested = most_tested(&snapshot, side);
+
+            assert!(
+                tested <= 9,
+                "a query tested {tested} of {} zones",
+                side * side
+            );
+        }
+    }
+
+    #[test]
+    fn an_outlier_does_not_grow_the_cells() {
+        let side = 30;
+        let outlier = Rectangle::new(
+            Point::new(1e7, 1e7),
+            Size::new(ZONE_SIZE, ZONE_SIZE),
+        );
+        let snapshot = Snapshot::from_bounds(
+            board(side).into_iter().chain([(side * side, outlier)]),
+        )
+        .index();
+
+        assert_eq!(snapshot.grid.as_ref().unwrap().cell_size, ZONE_SIZE);
+        assert!(most_tested(&snapshot, side) <= 9);
+        assert_eq!(snapshot.at(outlier.center()), [(side * side, outlier)]);
+    }
+
+    #[test]
+    fn indexed_queries_find_the_same_zones() {
+        let linear = Snapshot::from_bounds(board(40));
+        let indexed = Snapshot::from_bounds(board(40)).index();
+        assert!(indexed.is_indexed());
+
+        for point in points(40) {
+            let dragged = Rectangle::new(point, Size::new(30.0, 30.0));
+
+            assert_eq!(indexed.at(point), linear.at(point));
+            assert_eq!(
+                indexed.intersecting(dragged),
+                linear.intersecting(dragged)
+            );
+            assert_eq!(
+                indexed.hit(&PointerWithin, point, dragged, None),
+                linear.hit(&PointerWithin, point, dragged, None)
+            );
+            assert_eq!(
+                indexed.hit(&RectIntersection, point, dragged, None),
+                linear.hit(&RectIntersection, point, dragged, None)
+            );
+        }
+    }
+
+    #[test]
+    fn zones_without_finite_bounds_are_still_found() {
+        let zones = [
+            (
+                0,
+                Rectangle::new(Point::ORIGIN, Size::new(ZONE_SIZE, ZONE_SIZE)),
+            ),
+            (1, Rectangle::new(Point::ORIGIN, Size::INFINITE)),
+            (
+                2,
+                Rectangle::new(Point::new(f32::NAN, 0.0), Size::new(1.0, 1.0)),
+            ),
+        ];
+        let linear = Snapshot::from_bounds(zones);
+        let indexed = Snapshot::from_bounds(zones).index();
+
+        for point in [Point::new(10.0, 10.0), Point::new(500.0, 500.0)] {
+            assert_eq!(indexed.at(point), linear.at(point));
+        }
+        assert_eq!(indexed.at(Point::new(500.0, 500.0)).len(), 1);
+    }
+}
@@ -1,2 +1,5 @@
+pub mod drop_zone;
 pub mod droppable;
+pub mod keyed;
 pub mod operation;
+pub mod sortable;
@@ -0,0 +1,281 @@
+//! Encapsulates a widget that marks an area where a [`Droppable`] can be dropped.
+//!
+//! [`Droppable`]: crate::widget::droppable::Droppable
+use std::any::Any;
+use std::sync::Arc;
+
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::Cursor;
+use iced_core::renderer::Style;
+use iced_core::widget::tree::Tag;
+use iced_core::widget::{Id, Operation, Tree};
+use iced_core::{
+    Element, Event, Layout, Length, Rectangle, Size, Vector, Widget, mouse,
+    overlay, renderer,
+};
+
+use crate::widget::droppable::DropEffect;
+
+/// A container that can be found by the typed drop zone operations.
+///
+/// Unlike a plain container with an [`Id`], a [`DropZone`] carries a key of
+/// any clonable type, so the zones found under a [`Droppable`] can be mapped
+/// back to the application's model directly.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub struct DropZone<
+    'a,
+    K,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> where
+    K: Clone + PartialEq + Send + 'static,
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    key: K,
+    id: Option<Id>,
+    accept: Option<Accept>,
+    effects: Option<Vec<DropEffect>>,
+}
+
+impl<'a, K, Message, Theme, Renderer> DropZone<'a, K, Message, Theme, Renderer>
+where
+    K: Clone + PartialEq + Send + 'static,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`DropZone`] with the given key.
+    pub fn new(
+        key: K,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            key,
+            id: None,
+            accept: None,
+            effects: None,
+        }
+    }
+
+    /// Sets the unique identifier of the [`DropZone`].
+    ///
+    /// This is only needed if the [`DropZone`] should also be found by the
+    /// untyped [`find_zones`] operation.
+    ///
+    /// [`find_zones`]: crate::widget::operation::drop::find_zones
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets which payloads the [`DropZone`] accepts.
+    ///
+    /// This lets the [`DropZone`] inspect the payload of the [`Droppable`] being dragged.
+    /// When the zones are found with [`find_accepting_zones`], a [`DropZone`] is only returned
+    /// if it accepts the payload. Payloads of any other type are rejected.
+    ///
+    /// [`Droppable`]: crate::widget::droppable::Droppable
+    /// [`find_accepting_zones`]: crate::widget::operation::drop::find_accepting_zones
+    pub fn accept<P>(
+        mut self,
+        accept: impl Fn(&P) -> bool + Send + Sync + 'static,
+    ) -> Self
+    where
+        P: 'static,
+    {
+        self.accept = Some(Arc::new(move |payload: &dyn Any| {
+            payload.downcast_ref::<P>().is_some_and(&accept)
+        }));
+        self
+    }
+
+    /// Sets the [`DropEffect`]s the [`DropZone`] accepts.
+    ///
+    /// When the zones are found by the [`DragDrop`] controller, a [`DropZone`] is only returned
+    /// if it accepts the effect chosen for the drag. By default, any effect is accepted.
+    ///
+    /// [`DragDrop`]: crate::drag_drop::DragDrop
+    pub fn effects(mut self, effects: impl Into<Vec<DropEffect>>) -> Self {
+        self.effects = Some(effects.into());
+        self
+    }
+}
+
+impl<'a, K, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'a, K, Message, Theme, Renderer>
+where
+    K: Clone + PartialEq + Send + 'static,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::stateless()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut target = Target {
+            key: self.key.clone(),
+            accept: self.accept.clone(),
+            effects: self.effects.clone(),
+        };
+        operation.custom(self.id.as_ref(), layout.bounds(), &mut target);
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_core::Clipboard,
+        shell: &mut iced_core::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, K, Message, Theme, Renderer>
+    From<DropZone<'a, K, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    K: Clone + PartialEq + Send + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drop_zone: DropZone<'a, K, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(drop_zone)
+    }
+}
+
+type Accept = Arc<dyn Fn(&dyn Any) -> bool + Send + Sync>;
+
+/// The custom state a [`DropZone`] exposes to operations.
+pub(crate) struct Target<K> {
+    pub(crate) key: K,
+    accept: Option<Accept>,
+    pub(crate) effects: Option<Vec<DropEffect>>,
+}
+
+impl<K> Target<K> {
+    /// Returns whether the [`DropZone`] accepts the given payload.
+    pub(crate) fn accepts(&self, payload: &dyn Any) -> bool {
+        self.accept.as_ref().is_none_or(|accept| accept(payload))
+    }
+
+    /// Returns whether the [`DropZone`] accepts the given [`DropEffect`].
+    pub(crate) fn accepts_effect(&self, effect: DropEffect) -> bool {
+        self.effects
+            .as_ref()
+            .is_none_or(|effects| effects.contains(&effect))
+    }
+}
@@ -1,39 +1,81 @@
 //! Encapsulates a widget that can be dragged and dropped.
+use iced_core::keyboard::{self, key};
 use iced_core::layout::{Limits, Node};
+use iced_core::widget::operation::Focusable;
 use iced_core::widget::{Id, Operation, Tree};
-use iced_core::{mouse, overlay, renderer, window, Element, Event, Layout, Length, Pixels, Point, Rectangle, Size, Vector, Widget};
+use iced_core::time::{Duration, Instant};
+use iced_core::{mouse, overlay, renderer, touch, window, Background, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Size, Vector, Widget};
 use std::fmt::Debug;
 use std::vec;
 use iced_core::mouse::Cursor;
 use iced_core::renderer::Style;
 use iced_core::widget::tree::Tag;
 
+/// A callback producing a message from a payload and a [`DragEvent`].
+type DragFn<'a, Payload, Message> =
+    Box<dyn Fn(Payload, DragEvent) -> Message + 'a>;
+
+/// A function producing the element drawn under the cursor while dragging.
+type PreviewFn<'a, Message, Theme, Renderer> =
+    Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The length of the dashes of [`Placeholder::Outline`].
+const DASH_LENGTH: f32 = 8.0;
+
+/// The gap between the dashes of [`Placeholder::Outline`].
+const DASH_GAP: f32 = 6.0;
+
+/// The width of the dashes of [`Placeholder::Outline`].
+const DASH_WIDTH: f32 = 2.0;
+
+/// The distance the arrow keys move a [`Droppable`] without keyboard targets.
+const KEYBOARD_STEP: f32 = 10.0;
+
 /// An element that can be dragged and dropped on a [`DropZone`]
+///
+/// A [`Droppable`] can carry a payload, which is handed to its drag and drop callbacks.
+///
+/// A [`Droppable`] can also be dragged with the keyboard once it's focused: Space or Enter picks
+/// it up, the arrow keys or Tab move it between the zones loaded with [`load_keyboard_targets`],
+/// Enter drops it and Escape cancels the drag.
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+/// [`load_keyboard_targets`]: crate::widget::operation::drop::load_keyboard_targets
 pub struct Droppable<
     'a,
     Message,
     Theme = iced_widget::Theme,
     Renderer = iced_widget::Renderer,
+    Payload = (),
 > where
     Message: Clone,
     Renderer: renderer::Renderer,
 {
     content: Element<'a, Message, Theme, Renderer>,
     id: Option<Id>,
+    payload: Payload,
     drag_threshold: f32,
     on_press: Option<Message>,
     on_click: Option<Message>,
     on_single_click: Option<Message>,
-    on_drop: Option<Box<dyn Fn(Point, Rectangle) -> Message + 'a>>,
-    on_drag: Option<Box<dyn Fn(Point, Rectangle) -> Message + 'a>>,
+    on_drop: Option<DragFn<'a, Payload, Message>>,
+    on_drag: Option<DragFn<'a, Payload, Message>>,
     on_cancel: Option<Message>,
+    /// Whether the droppable can be dragged without [`on_drop`](Droppable::on_drop)
+    draggable: bool,
     drag_mode: Option<(bool, bool)>,
     drag_overlay: bool,
-    drag_hide: bool,
+    placeholder: Placeholder<'a, Message, Theme, Renderer>,
     drag_center: bool,
     drag_size: Option<Size>,
-    reset_delay: usize,
-    status: Option<Status>,
+    reset_timeout: Duration,
+    long_press: Option<Duration>,
+    cancel_policy: CancelPolicy,
+    effect: DropEffect,
+    animation: Duration,
+    easing: Easing,
+    drag_preview: Option<PreviewFn<'a, Message, Theme, Renderer>>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
 }
 
 impl<'a, Message, Theme, Renderer> Droppable<'a, Message, Theme, Renderer>
@@ -48,6 +90,7 @@ where
         Self {
             content: content.into(),
             id: None,
+            payload: (),
             drag_threshold: 5.0,
             on_press: None,
             on_click: None,
@@ -55,16 +98,81 @@ where
             on_drop: None,
             on_drag: None,
             on_cancel: None,
+            draggable: false,
             drag_mode: Some((true, true)),
             drag_overlay: true,
-            drag_hide: false,
+            placeholder: Placeholder::Content,
             drag_center: false,
             drag_size: None,
-            reset_delay: 0,
-            status: None,
+            reset_timeout: Duration::ZERO,
+            long_press: None,
+            cancel_policy: CancelPolicy::default(),
+            effect: DropEffect::default(),
+            animation: Duration::ZERO,
+            easing: Easing::default(),
+            drag_preview: None,
+            preview: None,
+        }
+    }
+
+    /// Sets the payload of the [`Droppable`].
+    ///
+    /// The payload is handed to the [`on_drop`] and [`on_drag`] callbacks, so the
+    /// application doesn't need to capture its own identifier in them.
+    ///
+    /// [`on_drop`]: Droppable::on_drop
+    /// [`on_drag`]: Droppable::on_drag
+    pub fn payload<P>(
+        self,
+        payload: P,
+    ) -> Droppable<'a, Message, Theme, Renderer, P>
+    where
+        Message: 'a,
+    {
+        let on_drop = self.on_drop.map(|on_drop| {
+            Box::new(move |_: P, event| (on_drop)((), event))
+                as DragFn<'a, P, Message>
+        });
+        let on_drag = self.on_drag.map(|on_drag| {
+            Box::new(move |_: P, event| (on_drag)((), event))
+                as DragFn<'a, P, Message>
+        });
+
+        Droppable {
+            content: self.content,
+            id: self.id,
+            payload,
+            drag_threshold: self.drag_threshold,
+            on_press: self.on_press,
+            on_click: self.on_click,
+            on_single_click: self.on_single_click,
+            on_drop,
+            on_drag,
+            on_cancel: self.on_cancel,
+            draggable: self.draggable,
+            drag_mode: self.drag_mode,
+            drag_overlay: self.drag_overlay,
+            placeholder: self.placeholder,
+            drag_center: self.drag_center,
+            drag_size: self.drag_size,
+            reset_timeout: self.reset_timeout,
+            long_press: self.long_press,
+            cancel_policy: self.cancel_policy,
+            effect: self.effect,
+            animation: self.animation,
+            easing: self.easing,
+            drag_preview: self.drag_preview,
+            preview: self.preview,
         }
     }
+}
 
+impl<'a, Message, Theme, Renderer, Payload>
+    Droppable<'a, Message, Theme, Renderer, Payload>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
     /// Sets the unique identifier of the [`Droppable`].
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -102,39 +210,133 @@ where
 
     /// Sets the message that will be produced when the [`Droppable`] is dropped on a [`DropZone`].
     ///
-    /// Unless this is set, the [`Droppable`] will be disabled.
+    /// The message is given the payload and a [`DragEvent`] describing the drop. Unless this is
+    /// set, the [`Droppable`] will be disabled.
+    ///
+    /// [`DropZone`]: crate::widget::drop_zone::DropZone
     pub fn on_drop<F>(mut self, message: F) -> Self
     where
-        F: Fn(Point, Rectangle) -> Message + 'a,
+        F: Fn(Payload, DragEvent) -> Message + 'a,
     {
         self.on_drop = Some(Box::new(message));
         self
     }
 
     /// Sets the message that will be produced when the [`Droppable`] is dragged.
+    ///
+    /// The message is given the payload and a [`DragEvent`] describing the move.
     pub fn on_drag<F>(mut self, message: F) -> Self
     where
-        F: Fn(Point, Rectangle) -> Message + 'a,
+        F: Fn(Payload, DragEvent) -> Message + 'a,
     {
         self.on_drag = Some(Box::new(message));
         self
     }
 
-    /// Sets the message that will be produced when the user right clicks while dragging the [`Droppable`].
+    /// Sets the message that will be produced when the drag of the [`Droppable`] is cancelled.
+    ///
+    /// Which events cancel the drag is decided by the [`CancelPolicy`]. Escape always cancels
+    /// a drag with the keyboard.
     pub fn on_cancel(mut self, message: Message) -> Self {
         self.on_cancel = Some(message);
         self
     }
 
+    /// Sets whether the [`Droppable`] can be dragged without [`on_drop`], for widgets that
+    /// follow its [`State`] instead of its messages.
+    ///
+    /// [`on_drop`]: Droppable::on_drop
+    pub(crate) fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Sets the [`DropEffect`] of the [`Droppable`] when no modifier is held.
+    ///
+    /// For example, a palette whose items stay in place when they're dropped should use
+    /// [`DropEffect::Copy`]. The modifiers held during the drag can still choose another effect,
+    /// see [`DropEffect::from_modifiers`].
+    pub fn effect(mut self, effect: DropEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Sets the [`CancelPolicy`] of the [`Droppable`].
+    pub fn cancel_policy(mut self, cancel_policy: CancelPolicy) -> Self {
+        self.cancel_policy = cancel_policy;
+        self
+    }
+
+    /// Sets how long a finger has to press the [`Droppable`] before it can be dragged by touch.
+    ///
+    /// If the finger moves past the [`drag_threshold`] before then, the touch is left to other
+    /// widgets, so a list of droppables can still be scrolled with a finger.
+    ///
+    /// [`drag_threshold`]: Droppable::drag_threshold
+    pub fn long_press(mut self, long_press: Duration) -> Self {
+        self.long_press = Some(long_press);
+        self
+    }
+
+    /// Sets the duration of the animation gliding the [`Droppable`] back into place once it's
+    /// dropped or its drag is cancelled.
+    ///
+    /// A dropped [`Droppable`] glides back to where it was dragged from, unless it's settled into
+    /// a zone with [`settle`]. The [`DragDrop`] controller does so when it's dropped on a zone.
+    /// A zero duration, the default, disables the animation.
+    ///
+    /// [`settle`]: crate::widget::operation::drop::settle
+    /// [`DragDrop`]: crate::drag_drop::DragDrop
+    pub fn animation(mut self, animation: Duration) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets the [`Easing`] of the animation set with [`animation`].
+    ///
+    /// [`animation`]: Droppable::animation
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
     /// Sets whether the [`Droppable`] should be drawn under the cursor while dragging.
     pub fn drag_overlay(mut self, drag_overlay: bool) -> Self {
         self.drag_overlay = drag_overlay;
         self
     }
 
+    /// Sets the element drawn under the cursor while dragging, instead of the [`Droppable`]'s own
+    /// content.
+    ///
+    /// The element is only built while the [`Droppable`] is dragged. It has its own size, and is
+    /// centered on the bounds the dragged content would have.
+    pub fn drag_preview<F>(mut self, preview: F) -> Self
+    where
+        F: Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+    {
+        self.drag_preview = Some(Box::new(preview));
+        self
+    }
+
     /// Sets whether the [`Droppable`] should be hidden while dragging.
+    ///
+    /// This is a shorthand for [`Placeholder::Hidden`].
     pub fn drag_hide(mut self, drag_hide: bool) -> Self {
-        self.drag_hide = drag_hide;
+        self.placeholder = if drag_hide {
+            Placeholder::Hidden
+        } else {
+            Placeholder::Content
+        };
+        self
+    }
+
+    /// Sets what is drawn at the original position of the [`Droppable`] while dragging.
+    pub fn placeholder(
+        mut self,
+        placeholder: Placeholder<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        self.placeholder = placeholder;
         self
     }
 
@@ -156,26 +358,358 @@ where
         self
     }
 
-    /// Sets the number of frames/layout calls to wait before resetting the size of the [`Droppable`] after dropping.
+    /// Sets how long the [`Droppable`] may keep its [`drag_size`] after it's dropped, while
+    /// waiting to be moved to its new location.
     ///
-    /// This is useful for cases where the [`Droppable`] is being moved to a new location after some widget operation.
-    /// In this case, the [`Droppable`] will maintain the 'drag_size' for the given number of frames before resetting to its original size.
-    /// This prevents the [`Droppable`] from 'jumping' back to its original size before the new location is rendered which
-    /// prevents flickering.
+    /// The [`Droppable`] keeps the [`drag_size`] until it's laid out somewhere else than where it
+    /// was dropped from, or until the timeout expires. This prevents it from flickering back to
+    /// its original size before the application moves it. A zero timeout, the default, resets
+    /// the size as soon as it's dropped, and a timeout too long to end keeps the size until the
+    /// [`Droppable`] moves.
     ///
-    /// Warning: this should only be set if there is some noticeable flickering when the [`Droppable`] is dropped. That is, if the
-    /// [`Droppable`] returns to its original size before it's moved to its new location.
-    pub fn reset_delay(mut self, reset_delay: usize) -> Self {
-        self.reset_delay = reset_delay;
+    /// [`drag_size`]: Droppable::drag_size
+    pub fn reset_timeout(mut self, reset_timeout: Duration) -> Self {
+        self.reset_timeout = reset_timeout;
         self
     }
 }
 
-impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for Droppable<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer, Payload>
+    Droppable<'a, Message, Theme, Renderer, Payload>
 where
     Message: Clone,
     Renderer: renderer::Renderer,
+    Payload: Clone,
+{
+    /// Handles a key press, dragging the [`Droppable`] with the keyboard.
+    fn on_key(
+        &self,
+        state: &mut State,
+        key: key::Named,
+        modifiers: keyboard::Modifiers,
+        layout: Layout<'_>,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        if !self.is_draggable() {
+            return;
+        }
+
+        if !state.keyboard {
+            if state.focused
+                && state.action == Action::None
+                && matches!(key, key::Named::Space | key::Named::Enter)
+            {
+                // pick up the droppable where it is
+                let bounds = layout.bounds();
+                let center = bounds.center();
+                state.widget_pos = bounds.position();
+                state.overlay_bounds = bounds;
+                state.action = Action::Drag(center, center);
+                state.keyboard = true;
+                state.target = None;
+                state.begin(center);
+
+                self.drag_to(state, center, shell);
+                shell.capture_event();
+                shell.invalidate_layout();
+            }
+            return;
+        }
+
+        let Action::Drag(_, current) = state.action else {
+            return;
+        };
+
+        let count = state.targets.len();
+        let (forward, step) = match key {
+            key::Named::ArrowDown => {
+                (true, Some(Vector::new(0.0, KEYBOARD_STEP)))
+            }
+            key::Named::ArrowRight => {
+                (true, Some(Vector::new(KEYBOARD_STEP, 0.0)))
+            }
+            key::Named::ArrowUp => {
+                (false, Some(Vector::new(0.0, -KEYBOARD_STEP)))
+            }
+            key::Named::ArrowLeft => {
+                (false, Some(Vector::new(-KEYBOARD_STEP, 0.0)))
+            }
+            key::Named::Tab => (!modifiers.shift(), None),
+            key::Named::Enter => {
+                state.keyboard = false;
+                self.release(state, layout, shell);
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            key::Named::Escape => {
+                self.cancel(state, shell);
+                shell.capture_event();
+                return;
+            }
+            _ => return,
+        };
+        let position = if count > 0 {
+            // cycle through the keyboard targets
+            let target = match (state.target, forward) {
+                (None, true) => 0,
+                (None, false) => count - 1,
+                (Some(i), true) => (i + 1) % count,
+                (Some(i), false) => (i + count - 1) % count,
+            };
+            state.target = Some(target);
+            state.targets[target].center()
+        } else if let Some(step) = step {
+            // without targets, the arrow keys move the droppable freely
+            current + step
+        } else {
+            return;
+        };
+
+        state.overlay_bounds.x = position.x - state.overlay_bounds.width / 2.0;
+        state.overlay_bounds.y = position.y - state.overlay_bounds.height / 2.0;
+        if let Action::Drag(start, _) = state.action {
+            state.action = Action::Drag(start, position);
+        }
+
+        self.drag_to(state, position, shell);
+        shell.capture_event();
+    }
+
+    /// Selects the [`Droppable`] at the given position, before it's dragged.
+    fn press(
+        &self,
+        state: &mut State,
+        position: Point,
+        layout: Layout<'_>,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        // select the droppable and store the position of the widget before dragging
+        state.action = Action::Select(position);
+        let bounds = layout.bounds();
+        state.widget_pos = bounds.position();
+        state.overlay_bounds.width = bounds.width;
+        state.overlay_bounds.height = bounds.height;
+
+        if let Some(on_click) = self.on_click.clone() {
+            shell.publish(on_click);
+        }
+    }
+
+    /// Moves the pointer of a selected or dragged [`Droppable`] to the given position.
+    fn move_to(
+        &self,
+        state: &mut State,
+        mut position: Point,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        let should_drag = match state.action {
+            // Check if the pointer has moved from the initial press position
+            // (in case there was a micro-movement, might happen on trackpad)
+            Action::Select(start)
+                if position.distance(start) >= self.drag_threshold =>
+            {
+                state.action = Action::Drag(start, position);
+                state.begin(start);
+                true
+            }
+            Action::Drag(start, _) => {
+                state.action = Action::Drag(start, position);
+                true
+            }
+            _ => false,
+        };
+
+        if should_drag && let Action::Drag(start, _) = state.action {
+            // Apply drag mode constraints
+            if let Some((drag_x, drag_y)) = self.drag_mode {
+                position = Point {
+                    x: if drag_x { position.x } else { start.x },
+                    y: if drag_y { position.y } else { start.y },
+                };
+            }
+
+            // update the position of the overlay since the pointer was moved
+            if self.drag_center {
+                state.overlay_bounds.x =
+                    position.x - state.overlay_bounds.width / 2.0;
+                state.overlay_bounds.y =
+                    position.y - state.overlay_bounds.height / 2.0;
+            } else {
+                state.overlay_bounds.x =
+                    state.widget_pos.x + position.x - start.x;
+                state.overlay_bounds.y =
+                    state.widget_pos.y + position.y - start.y;
+            }
+
+            self.drag_to(state, position, shell);
+        }
+    }
+
+    /// Releases a selected or dragged [`Droppable`], dropping it if it was dragged.
+    fn release(
+        &self,
+        state: &mut State,
+        layout: Layout<'_>,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        match state.action {
+            Action::Select(_) => {
+                if let Some(on_single_click) = self.on_single_click.clone() {
+                    shell.publish(on_single_click);
+                }
+                state.action = Action::None;
+            }
+            Action::Drag(_, current) => {
+                if let Some(on_drop) = self.on_drop.as_deref() {
+                    let event = self.drag_event(state, current, Vector::ZERO);
+                    shell.publish((on_drop)(self.payload.clone(), event));
+                }
+                state.dropped = true;
+                self.settle(state, shell);
+
+                if self.reset_timeout.is_zero() {
+                    state.action = Action::None;
+                } else {
+                    let deadline =
+                        Instant::now().checked_add(self.reset_timeout);
+                    state.action = Action::Wait(layout.position(), deadline);
+                    if let Some(deadline) = deadline {
+                        shell.request_redraw_at(deadline);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Cancels the drag of a [`Droppable`], publishing [`on_cancel`] if it was being dragged.
+    ///
+    /// [`on_cancel`]: Droppable::on_cancel
+    fn cancel(
+        &self,
+        state: &mut State,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        let dragged = matches!(state.action, Action::Drag(_, _));
+
+        if matches!(state.action, Action::Select(_) | Action::Drag(_, _)) {
+            state.action = Action::None;
+        }
+        state.keyboard = false;
+        state.target = None;
+        state.finger = None;
+        state.pressed_at = None;
+
+        if dragged {
+            self.cancelled(state, shell);
+        }
+    }
+
+    /// Publishes the cancel message of a drag that was just cancelled, and glides the overlay
+    /// back into place.
+    fn cancelled(
+        &self,
+        state: &mut State,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        if let Some(on_cancel) = self.on_cancel.clone() {
+            shell.publish(on_cancel);
+        }
+
+        self.settle(state, shell);
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Publishes the drag message for the given cursor position.
+    fn drag_to(
+        &self,
+        state: &mut State,
+        position: Point,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        let delta = state.track(position);
+
+        if let Some(on_drag) = self.on_drag.as_deref() {
+            let event = self.drag_event(state, position, delta);
+            shell.publish((on_drag)(self.payload.clone(), event));
+        }
+        shell.request_redraw();
+    }
+
+    /// Returns whether the [`Droppable`] can be dragged.
+    fn is_draggable(&self) -> bool {
+        self.on_drop.is_some() || self.draggable
+    }
+
+    /// The elements whose trees are kept as children: the content, and the placeholder element.
+    fn elements(&self) -> Vec<&dyn Widget<Message, Theme, Renderer>> {
+        let mut elements = vec![self.content.as_widget()];
+        if let Placeholder::Element(placeholder) = &self.placeholder {
+            elements.push(placeholder.as_widget());
+        }
+        elements
+    }
+
+    /// Starts gliding the overlay from its current bounds back to the original position of the
+    /// [`Droppable`], if it's animated.
+    fn settle(
+        &self,
+        state: &mut State,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        if self.animation.is_zero() || !self.drag_overlay {
+            return;
+        }
+
+        state.settle = Some(Settle {
+            from: state.overlay_bounds,
+            to: Rectangle::new(state.widget_pos, state.overlay_bounds.size()),
+            started: None,
+        });
+        shell.request_redraw();
+    }
+
+    /// Describes the drag at the given cursor position.
+    fn drag_event(
+        &self,
+        state: &State,
+        point: Point,
+        delta: Vector,
+    ) -> DragEvent {
+        let start = match state.action {
+            Action::Drag(start, _) => start,
+            _ => point,
+        };
+
+        DragEvent {
+            id: self.id.clone(),
+            point,
+            start,
+            delta,
+            velocity: state.velocity,
+            elapsed: state
+                .started_at
+                .map(|started_at| started_at.elapsed())
+                .unwrap_or_default(),
+            modifiers: state.modifiers,
+            effect: DropEffect::from_modifiers(self.effect, state.modifiers),
+            bounds: state.overlay_bounds,
+            origin: Rectangle::new(
+                state.widget_pos,
+                state.overlay_bounds.size(),
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Payload> Widget<Message, Theme, Renderer>
+    for Droppable<'a, Message, Theme, Renderer, Payload>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Payload: Clone,
 {
     fn size(&self) -> Size<Length> {
         self.content.as_widget().size()
@@ -194,6 +728,22 @@ where
             limits,
         );
 
+        // The placeholder element is laid out in the bounds of the content, after its children
+        if let Placeholder::Element(placeholder) = &mut self.placeholder
+            && state.is_moving()
+        {
+            let size = self.drag_size.unwrap_or(content_node.size());
+            let placeholder_node = placeholder.as_widget_mut().layout(
+                &mut tree.children[1],
+                renderer,
+                &Limits::new(Size::ZERO, size),
+            );
+            let mut children = content_node.children().to_vec();
+            children.push(placeholder_node);
+
+            return Node::with_children(size, children);
+        }
+
         // Adjust the size of the original widget if it's being dragged or we're wating to reset the size
         if let Some(new_size) = self.drag_size {
             match state.action {
@@ -203,13 +753,7 @@ where
                         content_node.children().to_vec(),
                     );
                 }
-                Action::Wait(reveal_index) => {
-                    if reveal_index <= 1 {
-                        state.action = Action::None;
-                    } else {
-                        state.action = Action::Wait(reveal_index - 1);
-                    }
-
+                Action::Wait(_, _) => {
                     return Node::with_children(
                         new_size,
                         content_node.children().to_vec(),
@@ -233,21 +777,50 @@ where
         viewport: &Rectangle,
     ) {
         let state: &State = tree.state.downcast_ref::<State>();
-        if let Action::Drag(_, _) = state.action
-            && self.drag_hide
-        {
-            return;
-        }
+        let dragging = state.is_moving();
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor,
-            viewport,
-        );
+        match &self.placeholder {
+            Placeholder::Hidden if dragging => {}
+            Placeholder::Outline(color) if dragging => {
+                draw_outline(renderer, layout.bounds(), *color);
+            }
+            Placeholder::Element(placeholder) if dragging => {
+                if let Some(placeholder_layout) = layout.children().last() {
+                    placeholder.as_widget().draw(
+                        &tree.children[1],
+                        renderer,
+                        theme,
+                        style,
+                        placeholder_layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            }
+            placeholder => {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+
+                if let Placeholder::Tinted(color) = placeholder
+                    && dragging
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: layout.bounds(),
+                            ..renderer::Quad::default()
+                        },
+                        Background::Color(*color),
+                    );
+                }
+            }
+        }
     }
 
     fn tag(&self) -> Tag {
@@ -259,11 +832,24 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        self.elements().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content))
+        let state = tree.state.downcast_ref::<State>();
+        let elements = self.elements();
+
+        // the tree of the drag preview is kept while dragging, it's diffed when the preview is built
+        if self.drag_preview.is_some()
+            && state.is_moving()
+            && tree.children.len() > elements.len()
+        {
+            for (child, element) in tree.children.iter_mut().zip(elements) {
+                child.diff(element);
+            }
+        } else {
+            tree.diff_children(&elements)
+        }
     }
 
     fn operate(
@@ -275,6 +861,9 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
         operation.custom(self.id.as_ref(), layout.bounds(), state);
+        if self.is_draggable() {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        }
         operation.container(self.id.as_ref(), layout.bounds());
         operation.traverse(&mut |operation| {
             self.content.as_widget_mut().operate(
@@ -299,6 +888,69 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.modifiers = *modifiers;
+        }
+
+        // carry out the requests of the drag operations, which can't publish messages themselves
+        if let Some(request) = state.request.take() {
+            match request {
+                Request::Drag => match state.action {
+                    Action::Drag(_, current) if self.is_draggable() => {
+                        self.drag_to(state, current, shell);
+                        shell.invalidate_layout();
+                    }
+                    _ => state.action = Action::None,
+                },
+                Request::Cancel => self.cancelled(state, shell),
+                Request::Reset => {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && let Some(settle) = &mut state.settle
+        {
+            let (bounds, done) = settle.tick(*now, self.animation, self.easing);
+            state.overlay_bounds = bounds;
+            if done {
+                state.settle = None;
+                shell.invalidate_layout();
+            }
+            shell.request_redraw();
+        }
+
+        // the drag size is kept until the droppable is moved away from where it was dropped
+        if let Action::Wait(position, deadline) = state.action {
+            let expired = match (event, deadline) {
+                (
+                    Event::Window(window::Event::RedrawRequested(now)),
+                    Some(deadline),
+                ) => *now >= deadline,
+                _ => false,
+            };
+
+            if layout.position() != position || expired {
+                state.action = Action::None;
+                shell.invalidate_layout();
+            } else if let Some(deadline) = deadline {
+                shell.request_redraw_at(deadline);
+            }
+        }
+
+        // the drag message is published every frame while auto-scrolling, since the content moves
+        // under the cursor even if it rests
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && let Action::Drag(_, current) = state.action
+            && state.auto_scroll
+        {
+            self.drag_to(state, current, shell);
+        }
+
         if !matches!(state.action, Action::Drag(_, _)) {
             // handle the on event of the content first, in case that the droppable is nested
             self.content.as_widget_mut().update(
@@ -318,123 +970,99 @@ where
             }
         }
 
-        if let Some(on_drop) = self.on_drop.as_deref()
-            && let Event::Mouse(mouse) = event
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = event
+        {
+            self.on_key(state, *key, *modifiers, layout, shell);
+        }
+
+        if let Action::Drag(_, _) = state.action
+            && self.cancel_policy.cancels(event)
         {
-            match mouse {
-                mouse::Event::ButtonPressed(btn) => {
+            self.cancel(state, shell);
+            shell.capture_event();
+            return;
+        }
+
+        if self.is_draggable() && !state.keyboard {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(btn)) => {
+                    // like text inputs, droppables are focused by clicking them
+                    state.focused = *btn == mouse::Button::Left
+                        && cursor.is_over(layout.bounds());
+
                     if *btn == mouse::Button::Left
-                        && cursor.is_over(layout.bounds())
+                        && let Some(position) = cursor.position_over(layout.bounds())
                     {
-                        // select the droppable and store the position of the widget before dragging
-                        state.action =
-                            Action::Select(cursor.position().unwrap());
-                        let bounds = layout.bounds();
-                        state.widget_pos = bounds.position();
-                        state.overlay_bounds.width = bounds.width;
-                        state.overlay_bounds.height = bounds.height;
-
-                        if let Some(on_click) = self.on_click.clone() {
-                            shell.publish(on_click);
-                        }
+                        self.press(state, position, layout, shell);
                         shell.capture_event();
-                    } else if *btn == mouse::Button::Right
-                        && let Action::Drag(_, _) = state.action
-                    {
-                        state.action = Action::None;
-                        if let Some(on_cancel) = self.on_cancel.clone() {
-                            shell.publish(on_cancel);
-                        }
-
-                        shell.invalidate_layout();
-                        shell.request_redraw();
                     }
                 }
-                &mouse::Event::CursorMoved { mut position } => {
-                    let should_drag = match state.action {
-                        Action::Select(start) => {
-                            // Check if cursor has moved from the initial click position
-                            // (in case there was a micro-movement, might happen on trackpad)
-                            let distance = ((position.x - start.x).powi(2)
-                                + (position.y - start.y).powi(2))
-                            .sqrt();
-
-                            if distance >= self.drag_threshold {
-                                state.action = Action::Drag(start, position);
-                                true
-                            } else {
-                                false
-                            }
-                        }
-                        Action::Drag(start, _) => {
-                            state.action = Action::Drag(start, position);
-                            true
-                        }
-                        _ => false,
-                    };
-
-                    if should_drag && let Action::Drag(start, _) = state.action
-                    {
-                        // Apply drag mode constraints
-                        if let Some((drag_x, drag_y)) = self.drag_mode {
-                            position = Point {
-                                x: if drag_x { position.x } else { start.x },
-                                y: if drag_y { position.y } else { start.y },
-                            };
-                        }
-
-                        // update the position of the overlay since the cursor was moved
-                        if self.drag_center {
-                            state.overlay_bounds.x =
-                                position.x - state.overlay_bounds.width / 2.0;
-                            state.overlay_bounds.y =
-                                position.y - state.overlay_bounds.height / 2.0;
-                        } else {
-                            state.overlay_bounds.x =
-                                state.widget_pos.x + position.x - start.x;
-                            state.overlay_bounds.y =
-                                state.widget_pos.y + position.y - start.y;
-                        }
-
-                        // Send on_drag message
-                        if let Some(on_drag) = self.on_drag.as_deref() {
-                            let message =
-                                (on_drag)(position, state.overlay_bounds);
-                            shell.publish(message);
-                        }
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                    if state.finger.is_none() =>
+                {
+                    self.move_to(state, *position, shell);
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) if state.finger.is_none() => {
+                    self.release(state, layout, shell);
+                }
+                Event::Touch(touch::Event::FingerPressed { id, position })
+                    if state.finger.is_none()
+                        && layout.bounds().contains(*position) =>
+                {
+                    state.finger = Some(*id);
+                    self.press(state, *position, layout, shell);
 
-                        shell.request_redraw();
+                    // leave the touch to scrollables until the long press is over
+                    if self.long_press.is_some() {
+                        state.pressed_at = Some(Instant::now());
+                    } else {
+                        shell.capture_event();
                     }
                 }
-                mouse::Event::ButtonReleased(btn) => {
-                    if *btn == mouse::Button::Left {
-                        match state.action {
-                            Action::Select(_) => {
-                                if let Some(on_single_click) = self.on_single_click.clone() {
-                                    shell.publish(on_single_click);
-                                }
-                                state.action = Action::None;
-                            }
-                            Action::Drag(_, current) => {
-                                // send on drop msg
-                                let message = (on_drop)(current, state.overlay_bounds);
-                                shell.publish(message);
-
-                                if self.reset_delay == 0 {
-                                    state.action = Action::None;
-                                } else {
-                                    state.action = Action::Wait(self.reset_delay);
-                                }
-                            }
-                            _ => (),
+                Event::Touch(touch::Event::FingerMoved { id, position })
+                    if state.finger == Some(*id) =>
+                {
+                    if let Action::Select(start) = state.action
+                        && let Some(pressed_at) = state.pressed_at
+                        && self.long_press.is_some_and(|long_press| {
+                            pressed_at.elapsed() < long_press
+                        })
+                    {
+                        // the finger moved before the long press was over, so it's most likely
+                        // scrolling
+                        if position.distance(start) >= self.drag_threshold {
+                            state.action = Action::None;
+                            state.finger = None;
+                            state.pressed_at = None;
                         }
+                        return;
                     }
+
+                    self.move_to(state, *position, shell);
+                    shell.capture_event();
+                }
+                Event::Touch(touch::Event::FingerLifted { id, .. })
+                    if state.finger == Some(*id) =>
+                {
+                    state.finger = None;
+                    state.pressed_at = None;
+                    self.release(state, layout, shell);
+                }
+                Event::Touch(touch::Event::FingerLost { id, .. })
+                    if state.finger == Some(*id) =>
+                {
+                    self.cancel(state, shell);
                 }
                 _ => {}
             }
         }
-
-        let current_status = if self.on_drop.is_none() {
+        let current_status = if !self.is_draggable() {
             Status::Disabled
         } else if cursor.is_over(layout.bounds()) {
             if let Action::Drag(_, _) = state.action {
@@ -449,8 +1077,8 @@ where
         if let Event::Window(window::Event::RedrawRequested(_now)) =
             event
         {
-            self.status = Some(current_status);
-        } else if self.status.is_some_and(|status| status != current_status) {
+            state.status = current_status;
+        } else if state.status != current_status {
             shell.request_redraw();
         }
     }
@@ -466,7 +1094,13 @@ where
         let state = tree.state.downcast_ref::<State>();
 
         if let Action::Drag(_, _) = state.action {
-            return mouse::Interaction::Grabbing;
+            return match DropEffect::from_modifiers(self.effect, state.modifiers)
+            {
+                DropEffect::Move => mouse::Interaction::Grabbing,
+                DropEffect::Copy => mouse::Interaction::Copy,
+                DropEffect::Link => mouse::Interaction::Alias,
+                DropEffect::None => mouse::Interaction::NoDrop,
+            };
         }
 
         let child_interact = self.content.as_widget().mouse_interaction(
@@ -482,7 +1116,7 @@ where
         }
 
         if cursor.is_over(layout.bounds()) {
-            if self.on_drop.is_some() {
+            if self.is_draggable() {
                 if self.on_press.is_some() {
                     mouse::Interaction::Pointer
                 } else {
@@ -505,13 +1139,31 @@ where
         _translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state: &mut State = tree.state.downcast_mut::<State>();
-        if self.drag_overlay
-            && let Action::Drag(_, _) = state.action
-        {
+        if self.drag_overlay && state.is_moving() {
+            let overlay_bounds = state.overlay_bounds;
+
+            if let Some(drag_preview) = &self.drag_preview {
+                let index = self.elements().len();
+                let preview = self.preview.get_or_insert_with(drag_preview);
+                if let Some(preview_tree) = tree.children.get_mut(index) {
+                    preview_tree.diff(&*preview);
+                } else {
+                    tree.children.push(Tree::new(&*preview));
+                }
+
+                return Some(overlay::Element::new(Box::new(Overlay {
+                    content: preview,
+                    tree: &mut tree.children[index],
+                    overlay_bounds,
+                    own_size: true,
+                })));
+            }
+
             return Some(overlay::Element::new(Box::new(Overlay {
                 content: &mut self.content,
                 tree: &mut tree.children[0],
-                overlay_bounds: state.overlay_bounds,
+                overlay_bounds,
+                own_size: false,
             })));
         }
         self.content.as_widget_mut().overlay(
@@ -524,27 +1176,440 @@ where
     }
 }
 
-impl<'a, Message, Theme, Renderer> From<Droppable<'a, Message, Theme, Renderer>>
+impl<'a, Message, Theme, Renderer, Payload>
+    From<Droppable<'a, Message, Theme, Renderer, Payload>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
     Theme: 'a,
     Renderer: 'a + renderer::Renderer,
+    Payload: 'a + Clone,
 {
     fn from(
-        droppable: Droppable<'a, Message, Theme, Renderer>,
+        droppable: Droppable<'a, Message, Theme, Renderer, Payload>,
     ) -> Element<'a, Message, Theme, Renderer> {
         Element::new(droppable)
     }
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
+/// Describes a [`Droppable`] when it's dragged or dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEvent {
+    /// The identifier of the [`Droppable`], if it has one.
+    pub id: Option<Id>,
+    /// The position of the cursor.
+    pub point: Point,
+    /// The position of the cursor when the drag started.
+    pub start: Point,
+    /// How far the cursor moved since the last drag message.
+    pub delta: Vector,
+    /// The velocity of the cursor, in pixels per second.
+    pub velocity: Vector,
+    /// The time since the drag started.
+    pub elapsed: Duration,
+    /// The keyboard modifiers held down.
+    pub modifiers: keyboard::Modifiers,
+    /// The [`DropEffect`] chosen by the modifiers.
+    pub effect: DropEffect,
+    /// The bounds of the dragged [`Droppable`].
+    pub bounds: Rectangle,
+    /// The bounds of the [`Droppable`] before it was dragged.
+    pub origin: Rectangle,
+}
+
+#[derive(Default, Clone, PartialEq, Debug)]
 pub struct State {
     widget_pos: Point,
     overlay_bounds: Rectangle,
     action: Action,
+    focused: bool,
+    /// Whether the current drag is driven by the keyboard
+    keyboard: bool,
+    /// The zones the droppable can be moved between with the keyboard
+    targets: Vec<Rectangle>,
+    /// The index of the keyboard target the droppable is over
+    target: Option<usize>,
+    /// The finger dragging the droppable, if it's dragged by touch
+    finger: Option<touch::Finger>,
+    /// When the finger pressed the droppable, if it has to be long pressed
+    pressed_at: Option<Instant>,
+    /// When the drag started
+    started_at: Option<Instant>,
+    /// The last position of the pointer during the drag, and when it was there
+    last: Option<(Point, Instant)>,
+    /// The velocity of the pointer during the drag, in pixels per second
+    velocity: Vector,
+    modifiers: keyboard::Modifiers,
+    /// Whether the last drag ended with a drop, rather than being cancelled
+    dropped: bool,
+    /// The animation gliding the overlay into place after the drag
+    settle: Option<Settle>,
+    /// Whether a scrollable is auto-scrolled by the drag
+    auto_scroll: bool,
+    status: Status,
+    /// What a drag operation asked the droppable to do on its next event
+    request: Option<Request>,
 }
 
+impl State {
+    /// Starts tracking a drag at the given position.
+    fn begin(&mut self, position: Point) {
+        let now = Instant::now();
+        self.started_at = Some(now);
+        self.last = Some((position, now));
+        self.velocity = Vector::ZERO;
+        self.dropped = false;
+        self.settle = None;
+        self.auto_scroll = false;
+    }
+
+    /// Returns whether the overlay is shown, either because the droppable is dragged or because
+    /// it's gliding into place.
+    fn is_moving(&self) -> bool {
+        matches!(self.action, Action::Drag(_, _)) || self.settle.is_some()
+    }
+
+    /// Returns the current [`Action`] of the droppable.
+    pub fn action(&self) -> Action {
+        self.action
+    }
+
+    /// Returns the [`Status`] of the droppable when it was last drawn.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the bounds of the droppable while it's dragged.
+    pub(crate) fn overlay_bounds(&self) -> Rectangle {
+        self.overlay_bounds
+    }
+
+    /// Returns whether the last drag of the droppable ended with a drop, rather than being
+    /// cancelled.
+    pub(crate) fn dropped(&self) -> bool {
+        self.dropped
+    }
+
+    /// Starts dragging the droppable with the given bounds from the given point, as if it had
+    /// been pressed there and moved past the drag threshold.
+    pub(crate) fn start_drag(&mut self, bounds: Rectangle, point: Point) {
+        self.reset();
+        self.widget_pos = bounds.position();
+        self.overlay_bounds = bounds;
+        self.action = Action::Drag(point, point);
+        self.begin(point);
+        self.request = Some(Request::Drag);
+    }
+
+    /// Cancels the drag of the droppable, if it's dragged.
+    pub(crate) fn cancel_drag(&mut self) {
+        if let Action::Drag(_, _) = self.action {
+            self.action = Action::None;
+            self.keyboard = false;
+            self.target = None;
+            self.finger = None;
+            self.pressed_at = None;
+            self.request = Some(Request::Cancel);
+        }
+    }
+
+    /// Resets the droppable as if it was never pressed, without publishing any message.
+    pub(crate) fn reset(&mut self) {
+        self.action = Action::None;
+        self.keyboard = false;
+        self.target = None;
+        self.finger = None;
+        self.pressed_at = None;
+        self.settle = None;
+        self.auto_scroll = false;
+        self.request = Some(Request::Reset);
+    }
+
+    /// Sets whether a scrollable is auto-scrolled by the drag.
+    pub(crate) fn set_auto_scroll(&mut self, auto_scroll: bool) {
+        self.auto_scroll = auto_scroll;
+    }
+
+    /// Glides the overlay into the given bounds instead of its original position, if it's gliding
+    /// into place.
+    pub(crate) fn settle_into(&mut self, bounds: Rectangle) {
+        if let Some(settle) = &mut self.settle {
+            settle.from = self.overlay_bounds;
+            settle.to = bounds;
+            settle.started = None;
+        }
+    }
+
+    /// Tracks the pointer of a drag, returning how far it moved since the last position.
+    fn track(&mut self, position: Point) -> Vector {
+        let now = Instant::now();
+        let Some((last, at)) = self.last.replace((position, now)) else {
+            return Vector::ZERO;
+        };
+
+        let delta = position - last;
+        let elapsed = now.duration_since(at).as_secs_f32();
+        if elapsed > 0.0 {
+            self.velocity = delta * (1.0 / elapsed);
+        }
+        delta
+    }
+
+    /// Sets the zones the droppable can be moved between with the keyboard.
+    ///
+    /// The droppable stays on the zone it's over if it's still a target, so the targets can be
+    /// reloaded in the middle of a drag.
+    pub(crate) fn set_targets(&mut self, targets: Vec<Rectangle>) {
+        self.target = self
+            .target
+            .and_then(|target| self.targets.get(target))
+            .and_then(|current| {
+                targets.iter().position(|target| target == current)
+            });
+        self.targets = targets;
+    }
+}
+
+/// The animation of an overlay gliding into place.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Settle {
+    from: Rectangle,
+    to: Rectangle,
+    started: Option<Instant>,
+}
+
+impl Settle {
+    /// Advances the animation, returning the bounds of the overlay and whether it's done.
+    fn tick(
+        &mut self,
+        now: Instant,
+        duration: Duration,
+        easing: Easing,
+    ) -> (Rectangle, bool) {
+        let started = *self.started.get_or_insert(now);
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.duration_since(started).as_secs_f32() / duration.as_secs_f32())
+                .min(1.0)
+        };
+        let t = easing.apply(progress);
+
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+        let bounds = Rectangle {
+            x: lerp(self.from.x, self.to.x),
+            y: lerp(self.from.y, self.to.y),
+            width: lerp(self.from.width, self.to.width),
+            height: lerp(self.from.height, self.to.height),
+        };
+
+        (bounds, progress >= 1.0)
+    }
+}
+
+/// The easing of the animation gliding a [`Droppable`] into place.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// A constant speed.
+    Linear,
+    /// Fast at first, slowing down towards the end.
+    #[default]
+    EaseOut,
+    /// Slow at both ends, fast in the middle.
+    EaseInOut,
+    /// A custom function mapping the progress of the animation to the eased progress, both
+    /// between 0 and 1.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Eases the progress of an animation, between 0 and 1.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            // cubic
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Custom(easing) => easing(t),
+        }
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// What happens to the payload of a [`Droppable`] when it's dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DropEffect {
+    /// The payload is moved to the drop zone.
+    #[default]
+    Move,
+    /// The payload is copied to the drop zone, and stays where it was.
+    Copy,
+    /// The drop zone is linked to the payload.
+    Link,
+    /// Nothing happens.
+    None,
+}
+
+impl DropEffect {
+    /// Returns the [`DropEffect`] chosen by the given modifiers, falling back to `effect` when
+    /// no modifier is held.
+    ///
+    /// Like in most file managers, Ctrl (Cmd on macOS) and Shift together link, Ctrl or Alt
+    /// copies, and Shift moves.
+    pub fn from_modifiers(
+        effect: DropEffect,
+        modifiers: keyboard::Modifiers,
+    ) -> DropEffect {
+        if modifiers.command() && modifiers.shift() {
+            DropEffect::Link
+        } else if modifiers.command() || modifiers.alt() {
+            DropEffect::Copy
+        } else if modifiers.shift() {
+            DropEffect::Move
+        } else {
+            effect
+        }
+    }
+}
+
+/// Decides which events cancel the drag of a [`Droppable`].
+///
+/// By default, Escape, a right click and the window losing focus cancel the drag.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CancelPolicy {
+    /// Whether pressing Escape cancels the drag.
+    pub escape: bool,
+    /// The mouse button that cancels the drag, if any.
+    pub button: Option<mouse::Button>,
+    /// Whether the window losing focus cancels the drag.
+    pub unfocused: bool,
+    /// Whether the cursor leaving the window cancels the drag.
+    pub cursor_left: bool,
+}
+
+impl Default for CancelPolicy {
+    fn default() -> Self {
+        Self {
+            escape: true,
+            button: Some(mouse::Button::Right),
+            unfocused: true,
+            cursor_left: false,
+        }
+    }
+}
+
+impl CancelPolicy {
+    /// A [`CancelPolicy`] where no event cancels the drag.
+    pub const NEVER: Self = Self {
+        escape: false,
+        button: None,
+        unfocused: false,
+        cursor_left: false,
+    };
+
+    /// Returns whether the event cancels a drag.
+    fn cancels(&self, event: &Event) -> bool {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => self.escape,
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                self.button == Some(*button)
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => self.cursor_left,
+            Event::Window(window::Event::Unfocused) => self.unfocused,
+            _ => false,
+        }
+    }
+}
+
+/// What is drawn at the original position of a [`Droppable`] while it's dragged.
+#[derive(Default)]
+pub enum Placeholder<'a, Message, Theme, Renderer> {
+    /// The content is drawn as usual.
+    #[default]
+    Content,
+    /// Nothing is drawn.
+    Hidden,
+    /// The content is drawn under a quad of the given color, which should be translucent.
+    ///
+    /// The content itself keeps its opacity, but tinting it with the color behind the
+    /// [`Droppable`] makes it look faded out.
+    Tinted(Color),
+    /// A dashed outline of the bounds of the [`Droppable`] is drawn in the given color.
+    Outline(Color),
+    /// The given element is drawn in the bounds of the [`Droppable`].
+    Element(Element<'a, Message, Theme, Renderer>),
+}
+
+/// Draws a dashed outline along the edges of the bounds.
+fn draw_outline<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    let mut dash = |x: f32, y: f32, width: f32, height: f32| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(Point::new(x, y), Size::new(width, height)),
+                ..renderer::Quad::default()
+            },
+            Background::Color(color),
+        );
+    };
+
+    let right = bounds.x + bounds.width - DASH_WIDTH;
+    let bottom = bounds.y + bounds.height - DASH_WIDTH;
+
+    let mut x = bounds.x;
+    while x < bounds.x + bounds.width {
+        let length = DASH_LENGTH.min(bounds.x + bounds.width - x);
+        dash(x, bounds.y, length, DASH_WIDTH);
+        dash(x, bottom, length, DASH_WIDTH);
+        x += DASH_LENGTH + DASH_GAP;
+    }
+
+    let mut y = bounds.y;
+    while y < bounds.y + bounds.height {
+        let length = DASH_LENGTH.min(bounds.y + bounds.height - y);
+        dash(bounds.x, y, DASH_WIDTH, length);
+        dash(right, y, DASH_WIDTH, length);
+        y += DASH_LENGTH + DASH_GAP;
+    }
+}
+
+/// What a drag operation asked a droppable to do on its next event.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Request {
+    /// Publish the drag message of a drag started by an operation
+    Drag,
+    /// Publish the cancel message of a drag cancelled by an operation
+    Cancel,
+    /// Lay out and draw the droppable again after it was reset
+    Reset,
+}
+
+/// The interaction status of a [`Droppable`].
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
 pub enum Status {
     #[default]
@@ -554,6 +1619,7 @@ pub enum Status {
     Disabled,
 }
 
+/// What a [`Droppable`] is doing.
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
 pub enum Action {
     #[default]
@@ -562,10 +1628,11 @@ pub enum Action {
     Select(Point),
     /// (start pos, current pos)
     Drag(Point, Point),
-    /// (frames to wait)
-    Wait(usize),
+    /// (position when dropped, when to stop waiting if ever)
+    Wait(Point, Option<Instant>),
 }
 
+/// Draws some content under the cursor while it's being dragged.
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer,
@@ -573,6 +1640,8 @@ where
     content: &'b mut Element<'a, Message, Theme, Renderer>,
     tree: &'b mut Tree,
     overlay_bounds: Rectangle,
+    /// Whether the content takes its own size, centered on the overlay bounds
+    own_size: bool,
 }
 
 impl<'a, 'b, Message, Theme, Renderer>
@@ -581,7 +1650,23 @@ impl<'a, 'b, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer,
 {
-    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> Node {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        if self.own_size {
+            let node = Widget::<Message, Theme, Renderer>::layout(
+                self.content.as_widget_mut(),
+                self.tree,
+                renderer,
+                &Limits::new(Size::ZERO, bounds),
+            );
+            let size = node.size();
+            let center = self.overlay_bounds.center();
+
+            return node.move_to(Point::new(
+                center.x - size.width / 2.0,
+                center.y - size.height / 2.0,
+            ));
+        }
+
         Widget::<Message, Theme, Renderer>::layout(
             self.content.as_widget_mut(),
             self.tree,
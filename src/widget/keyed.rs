@@ -0,0 +1,256 @@
+//! Encapsulates a column whose children keep their state by key, even when they're reordered.
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::Cursor;
+use iced_core::renderer::Style;
+use iced_core::widget::tree::{self, Tag};
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    Element, Event, Layout, Length, Padding, Pixels, Rectangle, Size, Vector,
+    Widget, alignment, mouse, overlay, renderer,
+};
+
+/// A column of children identified by a key.
+///
+/// Widget states are kept by position, so when the model is reordered, a drag in progress, an
+/// animation or the state of a [`Droppable`] stays where its item was laid out. A keyed
+/// [`Column`] hands each child the state of the child that had its key, wherever it moved.
+///
+/// Children sharing a key get the states of that key in the order they were laid out.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub struct Column<
+    'a,
+    Key,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    keys: Vec<Key>,
+    column: iced_widget::Column<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a [`Column`] with the given keyed children.
+    pub fn new(
+        children: impl IntoIterator<
+            Item = (Key, Element<'a, Message, Theme, Renderer>),
+        >,
+    ) -> Self {
+        let (keys, children): (Vec<_>, Vec<_>) = children.into_iter().unzip();
+
+        Self {
+            keys,
+            column: iced_widget::Column::from_vec(children),
+        }
+    }
+
+    /// Sets the vertical spacing between the children of the [`Column`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column = self.column.spacing(amount);
+        self
+    }
+
+    /// Sets the padding of the [`Column`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.column = self.column.padding(padding);
+        self
+    }
+
+    /// Sets the width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.column = self.column.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Column`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.column = self.column.height(height);
+        self
+    }
+
+    /// Sets the maximum width of the [`Column`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.column = self.column.max_width(max_width);
+        self
+    }
+
+    /// Sets the horizontal alignment of the children of the [`Column`].
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.column = self.column.align_x(align);
+        self
+    }
+
+    /// Adds a keyed child to the [`Column`].
+    pub fn push(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.keys.push(key);
+        self.column = self.column.push(child);
+        self
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.column.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        if state.keys != self.keys {
+            // line the trees up with the new keys, so the column diffs each child against the
+            // tree of its key
+            let mut old: Vec<Option<(Key, Tree)>> = state
+                .keys
+                .drain(..)
+                .zip(tree.children.drain(..))
+                .map(Some)
+                .collect();
+
+            tree.children = self
+                .keys
+                .iter()
+                .map(|key| {
+                    old.iter_mut()
+                        .find(|old| {
+                            old.as_ref().is_some_and(|(old, _)| old == key)
+                        })
+                        .and_then(Option::take)
+                        .map(|(_, tree)| tree)
+                        // a new key, its tree is created by the diff of the column
+                        .unwrap_or_else(Tree::empty)
+                })
+                .collect();
+
+            state.keys.clone_from(&self.keys);
+        }
+
+        self.column.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.column.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.column.size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> Node {
+        self.column.layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.column.operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_core::Clipboard,
+        shell: &mut iced_core::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.column.update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.column
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.column
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.column
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<Column<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(column: Column<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(column)
+    }
+}
+
+/// The keys of the children the trees of a [`Column`] belong to, in order.
+struct State<Key> {
+    keys: Vec<Key>,
+}
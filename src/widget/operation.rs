@@ -1 +1,3 @@
+pub mod drag;
 pub mod drop;
+pub mod state;
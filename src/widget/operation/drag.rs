@@ -0,0 +1,153 @@
+use std::any::Any;
+
+use iced_core::widget::operation::Outcome;
+use iced_core::widget::{Id, Operation};
+use iced_core::{Point, Rectangle};
+
+use crate::widget::droppable::{self, Action, Status};
+
+/// Produces an [`Operation`] that will start dragging the [`Droppable`] with the given Id from
+/// the given point, as if it had been pressed there and moved past its drag threshold.
+///
+/// The [`Droppable`] is dragged as soon as the operation runs, and then follows the cursor like
+/// any other drag. Operations can't publish messages, so the drag message is published on
+/// the next event, which the `start_drag` task of the `helpers` feature asks for with a redraw.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn start_drag(droppable: Id, point: Point) -> impl Operation {
+    struct StartDrag {
+        droppable: Id,
+        point: Point,
+    }
+
+    impl Operation for StartDrag {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.start_drag(bounds, self.point);
+            }
+        }
+    }
+
+    StartDrag { droppable, point }
+}
+
+/// Produces an [`Operation`] that will cancel the drag of the [`Droppable`] with the given Id.
+///
+/// The drag ends as soon as the operation runs, and the cancel message is published on the next
+/// event, which the `cancel_drag` task of the `helpers` feature asks for with a redraw.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn cancel_drag(droppable: Id) -> impl Operation {
+    struct CancelDrag {
+        droppable: Id,
+    }
+
+    impl Operation for CancelDrag {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.cancel_drag();
+            }
+        }
+    }
+
+    CancelDrag { droppable }
+}
+
+/// Produces an [`Operation`] that will reset the [`Droppable`] with the given Id to
+/// [`Action::None`], as if it was never pressed. Unlike [`cancel_drag`], no message is published.
+///
+/// The `reset_drag` task of the `helpers` feature redraws the [`Droppable`] right away.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn reset_drag(droppable: Id) -> impl Operation {
+    struct ResetDrag {
+        droppable: Id,
+    }
+
+    impl Operation for ResetDrag {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.reset();
+            }
+        }
+    }
+
+    ResetDrag { droppable }
+}
+
+/// Produces an [`Operation`] that will output the current [`Action`] and [`Status`] of the
+/// [`Droppable`] with the given Id, or [`None`] if there's no such [`Droppable`].
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn drag_status(droppable: Id) -> impl Operation<Option<(Action, Status)>> {
+    struct DragStatus {
+        droppable: Id,
+        found: Option<(Action, Status)>,
+    }
+
+    impl Operation<Option<(Action, Status)>> for DragStatus {
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(
+                &mut dyn Operation<Option<(Action, Status)>>,
+            ),
+        ) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_ref::<droppable::State>()
+            {
+                self.found = Some((state.action(), state.status()));
+            }
+        }
+
+        fn finish(&self) -> Outcome<Option<(Action, Status)>> {
+            Outcome::Some(self.found)
+        }
+    }
+
+    DragStatus {
+        droppable,
+        found: None,
+    }
+}
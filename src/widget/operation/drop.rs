@@ -1,59 +1,147 @@
-use iced_core::{Rectangle, Vector};
-use iced_core::widget::{Id, Operation};
+use std::any::Any;
+use std::sync::Arc;
+
+use iced_core::{Point, Rectangle, Size, Vector};
+use iced_core::widget::{Id, Operation, operation};
+use iced_core::widget::operation::scrollable::AbsoluteOffset;
 use iced_core::widget::operation::{Outcome, Scrollable};
 
-/// Produces an [`Operation`] that will find the drop zones that pass a filter on the zone's bounds.
+use crate::collision::{Collision, rank};
+use crate::snapshot::{CachedZone, Snapshot};
+use crate::widget::drop_zone::Target;
+use crate::widget::droppable::{self, DropEffect};
+
+/// A drop zone found by [`find_zones`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    /// The Id of the drop zone.
+    pub id: Id,
+    /// The bounds of the drop zone, including the parts scrolled out of view.
+    pub bounds: Rectangle,
+    /// The part of the bounds visible in every scrollable containing the drop zone.
+    pub visible: Rectangle,
+    /// The Ids of the drop zones found around the drop zone, from the outermost to the drop zone
+    /// itself, like the paths of [`find_zone_paths`].
+    pub path: Vec<Id>,
+}
+
+impl Zone {
+    /// Returns the number of drop zones found around the drop zone.
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+
+    /// Returns the Id of the innermost drop zone found around the drop zone, if any.
+    pub fn parent(&self) -> Option<&Id> {
+        self.path.iter().rev().nth(1)
+    }
+}
+
+/// A [`DropZone`] with a key of type `K` found by [`find_typed_zones`] and the other typed zone
+/// finders.
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedZone<K> {
+    /// The key of the drop zone.
+    pub key: K,
+    /// The bounds of the drop zone, including the parts scrolled out of view.
+    pub bounds: Rectangle,
+    /// The part of the bounds visible in every scrollable containing the drop zone.
+    pub visible: Rectangle,
+    /// The keys of the drop zones found around the drop zone, from the outermost to the drop
+    /// zone itself.
+    pub path: Vec<K>,
+}
+
+impl<K> TypedZone<K> {
+    /// Returns the number of drop zones found around the drop zone.
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+
+    /// Returns the key of the innermost drop zone found around the drop zone, if any.
+    pub fn parent(&self) -> Option<&K> {
+        self.path.iter().rev().nth(1)
+    }
+}
+
+/// Produces an [`Operation`] that will find the drop zones whose visible bounds pass a filter.
 /// For any drop zone to be considered, the Element must have some Id.
+/// Drop zones scrolled completely out of view are never found.
 /// If `options` is `None`, all drop zones will be considered.
-/// Depth determines how deep into nested drop zones to go.
+/// Depth determines how deep into nested drop zones to go, in every branch of the widget tree.
 /// If 'depth' is `None`, nested dropzones will be fully explored
+///
+/// A zone is always found before the zones inside it. Each [`Zone`] also has its path, so the
+/// innermost or outermost zone under the cursor is the one with the highest or lowest depth.
 pub fn find_zones<F>(
     filter: F,
     options: Option<Vec<Id>>,
     depth: Option<usize>,
-) -> impl Operation<Vec<(Id, Rectangle)>>
+) -> impl Operation<Vec<Zone>>
 where
     F: Fn(&Rectangle) -> bool + Send + 'static,
 {
     struct FindDropZone<F> {
         filter: F,
         options: Option<Vec<Id>>,
-        zones: Vec<(Id, Rectangle)>,
+        zones: Vec<Zone>,
         max_depth: Option<usize>,
-        c_depth: usize,
-        offset: Vector,
-        goto_next: bool,
+        current: Level,
+        next: Level,
     }
 
-    impl<F> Operation<Vec<(Id, Rectangle)>> for FindDropZone<F>
+    impl<F> Operation<Vec<Zone>> for FindDropZone<F>
     where
         F: Fn(&Rectangle) -> bool + Send + 'static,
     {
         fn traverse(
             &mut self,
-            operate: &mut dyn FnMut(&mut dyn Operation<Vec<(Id, Rectangle)>>),
+            operate: &mut dyn FnMut(&mut dyn Operation<Vec<Zone>>),
         ) {
-            if self.goto_next {
+            let parent = self.current;
+            let explore = match &self.max_depth {
+                Some(m_depth) => self.next.depth < *m_depth,
+                None => true,
+            };
+            if explore {
+                self.current = self.next;
                 operate(self);
             }
+            self.current = parent;
+            self.next = parent;
         }
 
         fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.next = self.current;
             if let Some(id) = id {
                 let is_option = match &self.options {
                     Some(options) => options.contains(id),
                     None => true,
                 };
-                let bounds = bounds - self.offset;
-                if is_option && (self.filter)(&bounds) {
-                    self.c_depth += 1;
-                    self.zones.push((id.clone(), bounds));
+                let bounds = bounds - self.current.offset;
+                if is_option
+                    && let Some(visible) = self.current.clip(bounds)
+                    && (self.filter)(&visible)
+                {
+                    let mut path = self
+                        .current
+                        .parent
+                        .map(|parent| self.zones[parent].path.clone())
+                        .unwrap_or_default();
+                    path.push(id.clone());
+
+                    self.next.depth += 1;
+                    self.next.parent = Some(self.zones.len());
+                    self.zones.push(Zone {
+                        id: id.clone(),
+                        bounds,
+                        visible,
+                        path,
+                    });
                 }
             }
-            self.goto_next = match &self.max_depth {
-                Some(m_depth) => self.c_depth < *m_depth,
-                None => true,
-            };
         }
 
         fn scrollable(
@@ -64,12 +152,10 @@ where
             translation: Vector,
             _state: &mut dyn Scrollable,
         ) {
-            if (self.filter)(&bounds) {
-                self.offset += translation;
-            }
+            self.next = self.current.scroll(bounds, translation);
         }
 
-        fn finish(&self) -> Outcome<Vec<(Id, Rectangle)>> {
+        fn finish(&self) -> Outcome<Vec<Zone>> {
             Outcome::Some(self.zones.clone())
         }
     }
@@ -79,8 +165,683 @@ where
         options,
         zones: vec![],
         max_depth: depth,
-        c_depth: 0,
-        offset: Vector { x: 0.0, y: 0.0 },
-        goto_next: false,
+        current: Level::ROOT,
+        next: Level::ROOT,
+    }
+}
+
+/// Produces an [`Operation`] that will load the drop zones found by [`find_zones`] into the
+/// [`Droppable`] with the given [`Id`], so it can be moved between them with the keyboard.
+/// `options` and `depth` behave like they do in [`find_zones`].
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn load_keyboard_targets(
+    droppable: Id,
+    options: Option<Vec<Id>>,
+    depth: Option<usize>,
+) -> impl Operation {
+    struct LoadTargets<O> {
+        find: O,
+        droppable: Id,
+    }
+
+    /// Forwards a nested [`Operation`] to the zone finder.
+    struct Forward<'a>(&'a mut dyn Operation<Vec<Zone>>);
+
+    impl Operation for Forward<'_> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            self.0.traverse(&mut |find| operate(&mut Forward(find)));
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.0.container(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.0
+                .scrollable(id, bounds, content_bounds, translation, state);
+        }
+    }
+
+    impl<O> Operation for LoadTargets<O>
+    where
+        O: Operation<Vec<Zone>>,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            Forward(&mut self.find).traverse(operate);
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.find.container(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.find
+                .scrollable(id, bounds, content_bounds, translation, state);
+        }
+
+        fn finish(&self) -> Outcome<()> {
+            let Outcome::Some(zones) = self.find.finish() else {
+                return Outcome::None;
+            };
+            // a droppable with an id is found as a zone too
+            let targets = zones
+                .into_iter()
+                .filter(|zone| zone.id != self.droppable)
+                .map(|zone| zone.visible)
+                .collect();
+
+            Outcome::Chain(Box::new(SetTargets {
+                droppable: self.droppable.clone(),
+                targets,
+            }))
+        }
+    }
+
+    struct SetTargets {
+        droppable: Id,
+        targets: Vec<Rectangle>,
+    }
+
+    impl Operation for SetTargets {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.set_targets(std::mem::take(&mut self.targets));
+            }
+        }
+    }
+
+    LoadTargets {
+        find: find_zones(|_| true, options, depth),
+        droppable,
+    }
+}
+
+/// Produces an [`Operation`] that will glide the [`Droppable`] with the given Id into the given
+/// bounds, instead of back to where it was dragged from.
+///
+/// It only has an effect while the [`Droppable`] is gliding into place after a drop, so its
+/// [`animation`] must be set.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+/// [`animation`]: crate::widget::droppable::Droppable::animation
+pub fn settle(droppable: Id, bounds: Rectangle) -> impl Operation {
+    struct Settle {
+        droppable: Id,
+        bounds: Rectangle,
+    }
+
+    impl Operation for Settle {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.settle_into(self.bounds);
+            }
+        }
+    }
+
+    Settle { droppable, bounds }
+}
+
+/// Produces an [`Operation`] that will scroll the scrollables whose viewport contains the point,
+/// if the point is within `margin` of one of their edges. It outputs whether anything was
+/// scrolled.
+///
+/// A scrollable is scrolled by up to `step` pixels along each axis, the closer the point is to the
+/// edge the faster. If `droppable` is the Id of a dragged [`Droppable`], it's told whether anything
+/// is still scrolling, so it keeps publishing its drag message while the cursor rests near an edge.
+///
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn auto_scroll(
+    droppable: Option<Id>,
+    point: Point,
+    margin: f32,
+    step: f32,
+) -> impl Operation<bool> {
+    struct AutoScroll {
+        droppable: Option<Id>,
+        point: Point,
+        margin: f32,
+        step: f32,
+        /// The translation of the scrollables containing the current widget
+        offset: Vector,
+        /// The translation of the last scrollable, applied to its content
+        translation: Vector,
+        scrolling: bool,
+    }
+
+    impl AutoScroll {
+        /// Returns how far to scroll along an axis, given the distance of the point to the start
+        /// and the end of the viewport.
+        fn speed(&self, start: f32, end: f32) -> f32 {
+            if start < self.margin && start < end {
+                -self.step * (1.0 - start.max(0.0) / self.margin)
+            } else if end < self.margin {
+                self.step * (1.0 - end.max(0.0) / self.margin)
+            } else {
+                0.0
+            }
+        }
+    }
+
+    impl Operation<bool> for AutoScroll {
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            let offset = self.offset;
+            self.offset = offset + std::mem::take(&mut self.translation);
+            operate(self);
+            self.offset = offset;
+        }
+
+        fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {
+            self.translation = Vector::ZERO;
+        }
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.translation = translation;
+
+            let viewport = bounds - self.offset;
+            if !viewport.contains(self.point) {
+                return;
+            }
+
+            let mut x = self.speed(
+                self.point.x - viewport.x,
+                viewport.x + viewport.width - self.point.x,
+            );
+            let mut y = self.speed(
+                self.point.y - viewport.y,
+                viewport.y + viewport.height - self.point.y,
+            );
+
+            // nothing is left to scroll past the ends of the content
+            let max = Vector::new(
+                content_bounds.width - bounds.width,
+                content_bounds.height - bounds.height,
+            );
+            if (x < 0.0 && translation.x <= 0.0)
+                || (x > 0.0 && translation.x >= max.x)
+            {
+                x = 0.0;
+            }
+            if (y < 0.0 && translation.y <= 0.0)
+                || (y > 0.0 && translation.y >= max.y)
+            {
+                y = 0.0;
+            }
+
+            if x != 0.0 || y != 0.0 {
+                state.scroll_by(
+                    AbsoluteOffset { x, y },
+                    bounds,
+                    content_bounds,
+                );
+                self.scrolling = true;
+            }
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            match &self.droppable {
+                Some(droppable) => Outcome::Chain(Box::new(SetAutoScroll {
+                    droppable: droppable.clone(),
+                    scrolling: self.scrolling,
+                })),
+                None => Outcome::Some(self.scrolling),
+            }
+        }
+    }
+
+    struct SetAutoScroll {
+        droppable: Id,
+        scrolling: bool,
+    }
+
+    impl Operation<bool> for SetAutoScroll {
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.droppable)
+                && let Some(state) = state.downcast_mut::<droppable::State>()
+            {
+                state.set_auto_scroll(self.scrolling);
+            }
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            Outcome::Some(self.scrolling)
+        }
+    }
+
+    AutoScroll {
+        droppable,
+        point,
+        margin,
+        step,
+        offset: Vector::ZERO,
+        translation: Vector::ZERO,
+        scrolling: false,
+    }
+}
+
+/// Produces an [`Operation`] that will find the [`DropZone`]s with a key of type `K` that pass a
+/// filter on the zone's visible bounds.
+/// Drop zones scrolled completely out of view are never found.
+/// If `options` is `None`, all drop zones with a key of type `K` will be considered.
+/// Depth determines how deep into nested drop zones to go.
+/// If 'depth' is `None`, nested dropzones will be fully explored
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+pub fn find_typed_zones<K, F>(
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Vec<TypedZone<K>>>
+where
+    K: Clone + PartialEq + Send + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    operation::map(FindTypedZone::new(filter, options, depth, None), zones)
+}
+
+/// Produces an [`Operation`] that will find the [`DropZone`]s with a key of type `K` that pass a
+/// filter on the zone's visible bounds, along with the keys of the zones around them.
+/// `options` and `depth` behave like they do in [`find_typed_zones`].
+///
+/// For each zone found, the path holds the keys of the zones found around it, from the outermost
+/// to the zone itself, so a drop on nested zones is resolved in one pass. Every [`TypedZone`]
+/// has its path, so this finds the same zones as [`find_typed_zones`].
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+pub fn find_zone_paths<K, F>(
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Vec<TypedZone<K>>>
+where
+    K: Clone + PartialEq + Send + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    find_typed_zones(filter, options, depth)
+}
+
+/// Produces an [`Operation`] that will take a [`Snapshot`] of the [`DropZone`]s with a key of type
+/// `K` that accept the given payload, so they can be hit-tested without walking the widget tree.
+/// `options` and `depth` behave like they do in [`find_typed_zones`].
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+pub fn snapshot_zones<K, P>(
+    payload: P,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Snapshot<K>>
+where
+    K: Clone + PartialEq + Send + 'static,
+    P: Send + 'static,
+{
+    operation::map(
+        FindTypedZone::new(
+            |_: &Rectangle| true,
+            options,
+            depth,
+            Some(Box::new(payload)),
+        ),
+        |hits| {
+            Snapshot::new(
+                hits.into_iter()
+                    .map(|hit| CachedZone {
+                        key: hit.key,
+                        bounds: hit.bounds,
+                        visible: hit.visible,
+                        effects: hit.effects,
+                    })
+                    .collect(),
+            )
+        },
+    )
+}
+
+/// Produces an [`Operation`] that will find the [`DropZone`]s with a key of type `K` that pass a
+/// filter on the zone's visible bounds and accept the given payload.
+/// A [`DropZone`] without an [`accept`] predicate accepts any payload.
+/// `options` and `depth` behave like they do in [`find_typed_zones`].
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+/// [`accept`]: crate::widget::drop_zone::DropZone::accept
+pub fn find_accepting_zones<K, P, F>(
+    payload: P,
+    filter: F,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Vec<TypedZone<K>>>
+where
+    K: Clone + PartialEq + Send + 'static,
+    P: Send + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    operation::map(
+        FindTypedZone::new(filter, options, depth, Some(Box::new(payload))),
+        zones,
+    )
+}
+
+/// Produces an [`Operation`] that will find the [`DropZone`]s with a key of type `K` that collide
+/// with a dragged [`Droppable`], ranked from the best to the worst target.
+/// `pointer` is the cursor position and `dragged` the bounds of the dragged [`Droppable`].
+/// `options` and `depth` behave like they do in [`find_typed_zones`].
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+/// [`Droppable`]: crate::widget::droppable::Droppable
+pub fn find_ranked_zones<K, C>(
+    collision: C,
+    pointer: Point,
+    dragged: Rectangle,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Vec<TypedZone<K>>>
+where
+    K: Clone + PartialEq + Send + 'static,
+    C: Collision + Send + Sync + 'static,
+{
+    ranked_zones(
+        Arc::new(collision),
+        pointer,
+        dragged,
+        None,
+        None,
+        options,
+        depth,
+    )
+}
+
+/// Like [`find_ranked_zones`], but only [`DropZone`]s accepting the payload and the
+/// [`DropEffect`] are considered.
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+pub(crate) fn ranked_zones<K>(
+    collision: Arc<dyn Collision + Send + Sync>,
+    pointer: Point,
+    dragged: Rectangle,
+    payload: Option<Box<dyn Any + Send>>,
+    effect: Option<DropEffect>,
+    options: Option<Vec<K>>,
+    depth: Option<usize>,
+) -> impl Operation<Vec<TypedZone<K>>>
+where
+    K: Clone + PartialEq + Send + 'static,
+{
+    let filter = {
+        let collision = collision.clone();
+        move |bounds: &Rectangle| {
+            collision.collide(pointer, dragged, *bounds).is_some()
+        }
+    };
+
+    operation::map(
+        FindTypedZone {
+            effect,
+            ..FindTypedZone::new(filter, options, depth, payload)
+        },
+        // zones are ranked on the bounds they were filtered on
+        move |hits| {
+            let zones = zones(hits)
+                .into_iter()
+                .map(|zone| {
+                    let visible = zone.visible;
+                    (zone, visible)
+                })
+                .collect();
+
+            rank(collision.as_ref(), pointer, dragged, zones)
+                .into_iter()
+                .map(|(zone, _)| zone)
+                .collect()
+        },
+    )
+}
+
+/// The position of a widget in the widget tree, as seen by the zone finders.
+#[derive(Clone, Copy)]
+struct Level {
+    /// The number of zones found around the widget
+    depth: usize,
+    /// The index of the innermost zone found around the widget
+    parent: Option<usize>,
+    /// The translation of the scrollables containing the widget
+    offset: Vector,
+    /// The part of the window visible through the scrollables containing the widget
+    viewport: Option<Rectangle>,
+}
+
+impl Level {
+    const ROOT: Level = Level {
+        depth: 0,
+        parent: None,
+        offset: Vector::ZERO,
+        viewport: None,
+    };
+
+    /// Returns the level of the content of a scrollable with the given bounds and translation.
+    fn scroll(self, bounds: Rectangle, translation: Vector) -> Level {
+        let bounds = bounds - self.offset;
+        let viewport = match self.viewport {
+            Some(viewport) => viewport
+                .intersection(&bounds)
+                .unwrap_or(Rectangle::new(bounds.position(), Size::ZERO)),
+            None => bounds,
+        };
+
+        Level {
+            offset: self.offset + translation,
+            viewport: Some(viewport),
+            ..self
+        }
+    }
+
+    /// Returns the visible part of the given bounds, if any.
+    fn clip(&self, bounds: Rectangle) -> Option<Rectangle> {
+        match self.viewport {
+            Some(viewport) => viewport.intersection(&bounds),
+            None => Some(bounds),
+        }
+    }
+}
+
+/// A [`DropZone`] found by [`FindTypedZone`].
+///
+/// [`DropZone`]: crate::widget::drop_zone::DropZone
+#[derive(Clone)]
+struct Hit<K> {
+    key: K,
+    bounds: Rectangle,
+    visible: Rectangle,
+    effects: Option<Vec<DropEffect>>,
+    /// The index of the innermost zone found around this one
+    parent: Option<usize>,
+}
+
+/// Returns the zones found, with their paths.
+fn zones<K: Clone>(hits: Vec<Hit<K>>) -> Vec<TypedZone<K>> {
+    // a zone is always found after the zones around it
+    let mut paths: Vec<Vec<K>> = Vec::with_capacity(hits.len());
+    for hit in &hits {
+        let mut path = hit
+            .parent
+            .map(|parent| paths[parent].clone())
+            .unwrap_or_default();
+        path.push(hit.key.clone());
+        paths.push(path);
+    }
+
+    paths
+        .into_iter()
+        .zip(hits)
+        .map(|(path, hit)| TypedZone {
+            key: hit.key,
+            bounds: hit.bounds,
+            visible: hit.visible,
+            path,
+        })
+        .collect()
+}
+
+struct FindTypedZone<K, F> {
+    filter: F,
+    options: Option<Vec<K>>,
+    payload: Option<Box<dyn Any + Send>>,
+    effect: Option<DropEffect>,
+    zones: Vec<Hit<K>>,
+    max_depth: Option<usize>,
+    current: Level,
+    next: Level,
+}
+
+impl<K, F> FindTypedZone<K, F> {
+    fn new(
+        filter: F,
+        options: Option<Vec<K>>,
+        depth: Option<usize>,
+        payload: Option<Box<dyn Any + Send>>,
+    ) -> Self {
+        FindTypedZone {
+            filter,
+            options,
+            payload,
+            effect: None,
+            zones: vec![],
+            max_depth: depth,
+            current: Level::ROOT,
+            next: Level::ROOT,
+        }
+    }
+}
+
+impl<K, F> Operation<Vec<Hit<K>>> for FindTypedZone<K, F>
+where
+    K: Clone + PartialEq + Send + 'static,
+    F: Fn(&Rectangle) -> bool + Send + 'static,
+{
+    fn traverse(
+        &mut self,
+        operate: &mut dyn FnMut(&mut dyn Operation<Vec<Hit<K>>>),
+    ) {
+        let parent = self.current;
+        let explore = match &self.max_depth {
+            Some(m_depth) => self.next.depth < *m_depth,
+            None => true,
+        };
+        if explore {
+            self.current = self.next;
+            operate(self);
+        }
+        self.current = parent;
+        self.next = parent;
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        state: &mut dyn Any,
+    ) {
+        self.next = self.current;
+        if let Some(target) = state.downcast_ref::<Target<K>>() {
+            let is_option = match &self.options {
+                Some(options) => options.contains(&target.key),
+                None => true,
+            };
+            let accepts = match &self.payload {
+                Some(payload) => target.accepts(payload.as_ref()),
+                None => true,
+            };
+            let accepts = accepts
+                && self
+                    .effect
+                    .is_none_or(|effect| target.accepts_effect(effect));
+            let bounds = bounds - self.current.offset;
+            if is_option
+                && accepts
+                && let Some(visible) = self.current.clip(bounds)
+                && (self.filter)(&visible)
+            {
+                self.next.depth += 1;
+                self.next.parent = Some(self.zones.len());
+                self.zones.push(Hit {
+                    key: target.key.clone(),
+                    bounds,
+                    visible,
+                    effects: target.effects.clone(),
+                    parent: self.current.parent,
+                });
+            }
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn Scrollable,
+    ) {
+        self.next = self.current.scroll(bounds, translation);
+    }
+
+    fn finish(&self) -> Outcome<Vec<Hit<K>>> {
+        Outcome::Some(self.zones.clone())
     }
 }
@@ -0,0 +1,167 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use iced_core::Rectangle;
+use iced_core::widget::operation::Outcome;
+use iced_core::widget::{Id, Operation};
+
+/// Produces an [`Operation`] that will move the custom states of type `S` between widgets, so
+/// their states follow the items when the application reorders its model.
+///
+/// Each transfer `(from, to)` writes the state the widget `from` had into the widget `to`, as
+/// `modify(state of to, state of from)`. Every state is read before any is written, so the
+/// transfers can describe any permutation, like a swap or a rotation. Nothing is written if one of
+/// the `from` widgets isn't found.
+///
+/// [`swap_states`] and [`move_states`] produce the transfers of the most common reorders.
+pub fn transfer_states<S, F>(
+    transfers: Vec<(Id, Id)>,
+    modify: F,
+) -> impl Operation
+where
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    struct Read<S, F> {
+        transfers: Vec<(Id, Id)>,
+        modify: Arc<F>,
+        states: Vec<Option<S>>,
+    }
+
+    impl<S, F> Operation for Read<S, F>
+    where
+        S: Clone + Send + 'static,
+        F: Fn(&S, &S) -> S + Send + Sync + 'static,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            if self.states.iter().all(Option::is_some) {
+                return;
+            }
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            let (Some(id), Some(state)) = (id, state.downcast_ref::<S>())
+            else {
+                return;
+            };
+
+            for ((from, _), read) in
+                self.transfers.iter().zip(self.states.iter_mut())
+            {
+                if from == id {
+                    *read = Some(state.clone());
+                }
+            }
+        }
+
+        fn finish(&self) -> Outcome<()> {
+            let Some(states) = self.states.iter().cloned().collect() else {
+                return Outcome::None;
+            };
+
+            Outcome::Chain(Box::new(Write {
+                transfers: self.transfers.clone(),
+                modify: self.modify.clone(),
+                states,
+                written: vec![false; self.transfers.len()],
+            }))
+        }
+    }
+
+    struct Write<S, F> {
+        transfers: Vec<(Id, Id)>,
+        modify: Arc<F>,
+        states: Vec<S>,
+        written: Vec<bool>,
+    }
+
+    impl<S, F> Operation for Write<S, F>
+    where
+        S: Clone + Send + 'static,
+        F: Fn(&S, &S) -> S + Send + Sync + 'static,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            if self.written.iter().all(|written| *written) {
+                return;
+            }
+            operate(self);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            let (Some(id), Some(state)) = (id, state.downcast_mut::<S>())
+            else {
+                return;
+            };
+
+            for (((_, to), read), written) in self
+                .transfers
+                .iter()
+                .zip(&self.states)
+                .zip(self.written.iter_mut())
+            {
+                if to == id && !*written {
+                    *state = (self.modify)(state, read);
+                    *written = true;
+                }
+            }
+        }
+    }
+
+    Read {
+        states: vec![None; transfers.len()],
+        transfers,
+        modify: Arc::new(modify),
+    }
+}
+
+/// Produces an [`Operation`] that will swap the custom states of type `S` of two widgets, like
+/// [`transfer_states`].
+pub fn swap_states<S, F>(a: Id, b: Id, modify: F) -> impl Operation
+where
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    transfer_states(vec![(a.clone(), b.clone()), (b, a)], modify)
+}
+
+/// Produces an [`Operation`] that will move the custom states of type `S` of widgets laid out in
+/// the order of `ids`, after the item at index `from` was moved to index `to`, like
+/// [`transfer_states`].
+///
+/// The state at `from` goes to `to`, and the states in between shift by one towards `from`.
+pub fn move_states<S, F>(
+    ids: Vec<Id>,
+    from: usize,
+    to: usize,
+    modify: F,
+) -> impl Operation
+where
+    S: Clone + Send + 'static,
+    F: Fn(&S, &S) -> S + Send + Sync + 'static,
+{
+    let mut order: Vec<usize> = (0..ids.len()).collect();
+    if from < ids.len() && to < ids.len() {
+        let moved = order.remove(from);
+        order.insert(to, moved);
+    }
+
+    let transfers = order
+        .into_iter()
+        .enumerate()
+        .filter(|(index, source)| index != source)
+        .map(|(index, source)| (ids[source].clone(), ids[index].clone()))
+        .collect();
+
+    transfer_states(transfers, modify)
+}
@@ -0,0 +1,1146 @@
+//! Encapsulates a widget whose items can be reordered by dragging them.
+use std::any::Any;
+
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::Cursor;
+use iced_core::renderer::Style;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::tree::{self, Tag};
+use iced_core::widget::{Id, Operation, Tree};
+use iced_core::{
+    Element, Event, Layout, Length, Pixels, Point, Rectangle, Size, Vector,
+    Widget, mouse, overlay, renderer, window,
+};
+
+use crate::widget::droppable::{
+    self, Action, CancelPolicy, Droppable, Placeholder,
+};
+
+/// The direction in which the items of a [`Sortable`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// The items are laid out in a column.
+    #[default]
+    Vertical,
+    /// The items are laid out in a row.
+    Horizontal,
+    /// The items are laid out in rows, wrapping to a new row when the width runs out.
+    Grid,
+}
+
+/// An item dragged from one [`Sortable`] and dropped on another [`Sortable`] of the same group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    /// The identifier of the [`Sortable`] the item was dragged from.
+    pub from_list: Id,
+    /// The index the item was dragged from.
+    pub from_index: usize,
+    /// The identifier of the [`Sortable`] the item was dropped on.
+    pub to_list: Id,
+    /// The index the item should be inserted at.
+    pub to_index: usize,
+}
+
+/// A list of items that can be reordered by dragging them.
+///
+/// Each item is a [`Droppable`], so it's dragged with the mouse, touch or the keyboard like any
+/// other. While an item is dragged, the other items move out of its way, and once it's dropped
+/// [`on_reorder`] is published with its old and new index. Items can also be dragged between
+/// lists that share a [`group`], which publishes [`on_transfer`] instead.
+///
+/// [`on_reorder`]: Sortable::on_reorder
+/// [`group`]: Sortable::group
+/// [`on_transfer`]: Sortable::on_transfer
+pub struct Sortable<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    rows: Vec<Droppable<'a, Message, Theme, Renderer>>,
+    id: Option<Id>,
+    direction: Direction,
+    spacing: f32,
+    width: Length,
+    height: Length,
+    animation: Duration,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    group: Option<String>,
+    on_transfer: Option<Box<dyn Fn(Transfer) -> Message + 'a>>,
+    on_drop_outside: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    inside: Vec<Id>,
+}
+
+impl<'a, Message, Theme, Renderer> Sortable<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Sortable`] with the given items.
+    pub fn new(
+        items: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            // the gap left by the dragged item is made by moving the other items
+            rows: items
+                .into_iter()
+                .map(|item| {
+                    Droppable::new(item).placeholder(Placeholder::Hidden)
+                })
+                .collect(),
+            id: None,
+            direction: Direction::default(),
+            spacing: 0.0,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            animation: Duration::from_millis(150),
+            on_reorder: None,
+            group: None,
+            on_transfer: None,
+            on_drop_outside: None,
+            inside: Vec::new(),
+        }
+    }
+
+    /// Sets the unique identifier of the [`Sortable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Direction`] in which the items of the [`Sortable`] are laid out.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the spacing between the items of the [`Sortable`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Sortable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Sortable`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the distance the cursor has to move before an item starts being dragged.
+    pub fn drag_threshold(self, drag_threshold: impl Into<Pixels>) -> Self {
+        let drag_threshold = drag_threshold.into();
+        self.map_rows(|row| row.drag_threshold(drag_threshold))
+    }
+
+    /// Sets the [`CancelPolicy`] of the items of the [`Sortable`].
+    pub fn cancel_policy(self, cancel_policy: CancelPolicy) -> Self {
+        self.map_rows(|row| row.cancel_policy(cancel_policy))
+    }
+
+    /// Sets how long a finger has to press an item before it can be dragged by touch, like
+    /// [`Droppable::long_press`].
+    pub fn long_press(self, long_press: Duration) -> Self {
+        self.map_rows(|row| row.long_press(long_press))
+    }
+
+    /// Sets how long the items take to move out of the way of the dragged item.
+    ///
+    /// A zero duration disables the animation.
+    pub fn animation(mut self, animation: Duration) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets the message that will be produced when an item is dropped at a new index.
+    ///
+    /// The message is given the index the item was dragged from and the index it should be moved
+    /// to, as in `items.insert(to, items.remove(from))`. Unless this is set, the items can't be
+    /// dragged.
+    pub fn on_reorder<F>(mut self, message: F) -> Self
+    where
+        F: Fn(usize, usize) -> Message + 'a,
+    {
+        self.on_reorder = Some(Box::new(message));
+        self.map_rows(|row| row.draggable(true))
+    }
+
+    /// Sets the group of the [`Sortable`].
+    ///
+    /// Items can be dragged between lists of the same group, while lists of other groups reject
+    /// them. A [`Sortable`] needs an [`id`] to join a group, since that's how [`Transfer`]s
+    /// identify it, and the lists of a group exchange their items through the [`Transfers`]
+    /// around them. An empty [`Sortable`] needs a size other than [`Length::Shrink`] to receive
+    /// items.
+    ///
+    /// [`id`]: Sortable::id
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets the message that will be produced when an item is dropped on another list of the same
+    /// [`group`].
+    ///
+    /// The message is only produced by the list the item was dragged from.
+    ///
+    /// [`group`]: Sortable::group
+    pub fn on_transfer<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Transfer) -> Message + 'a,
+    {
+        self.on_transfer = Some(Box::new(message));
+        self.map_rows(|row| row.draggable(true))
+    }
+
+    /// Sets the message that will be produced when an item is dropped outside of the
+    /// [`Sortable`], of any other list of its [`group`], and of the widgets set with [`inside`].
+    ///
+    /// The message is given the index of the item, so the application can remove it.
+    ///
+    /// [`group`]: Sortable::group
+    /// [`inside`]: Sortable::inside
+    pub fn on_drop_outside<F>(mut self, message: F) -> Self
+    where
+        F: Fn(usize) -> Message + 'a,
+    {
+        self.on_drop_outside = Some(Box::new(message));
+        self.map_rows(|row| row.draggable(true))
+    }
+
+    /// Sets the widgets, by [`Id`], an item can be dropped on without being dropped outside of
+    /// the [`Sortable`].
+    ///
+    /// The other lists of the [`group`] always count as inside, but the widgets around them, like
+    /// a header or an input adding items, don't unless they're set here. They're found by the
+    /// [`Transfers`] around the [`Sortable`], so a list without one only counts its own bounds.
+    ///
+    /// [`group`]: Sortable::group
+    pub fn inside(mut self, ids: impl IntoIterator<Item = Id>) -> Self {
+        self.inside = ids.into_iter().collect();
+        self
+    }
+
+    /// Applies a builder method to the [`Droppable`] of every item.
+    fn map_rows(
+        mut self,
+        f: impl Fn(
+            Droppable<'a, Message, Theme, Renderer>,
+        ) -> Droppable<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        self.rows = std::mem::take(&mut self.rows).into_iter().map(f).collect();
+        self
+    }
+
+    /// The [`Droppable`] of every item, as widgets.
+    fn rows(&self) -> Vec<&dyn Widget<Message, Theme, Renderer>> {
+        self.rows
+            .iter()
+            .map(|row| row as &dyn Widget<Message, Theme, Renderer>)
+            .collect()
+    }
+
+    /// Returns the index an item dragged by the given point would be dropped at, ignoring the
+    /// item at `skip`.
+    ///
+    /// The point is where the item is held rather than its center, so a large item lands where
+    /// the cursor is, wherever it was grabbed.
+    fn insertion(
+        &self,
+        layout: Layout<'_>,
+        point: Point,
+        skip: Option<usize>,
+    ) -> usize {
+        layout
+            .children()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != skip)
+            .filter(|(_, child)| {
+                let bounds = child.bounds();
+                match self.direction {
+                    Direction::Vertical => point.y > bounds.center_y(),
+                    Direction::Horizontal => point.x > bounds.center_x(),
+                    Direction::Grid => {
+                        point.y > bounds.y + bounds.height
+                            || (point.y >= bounds.y
+                                && point.x > bounds.center_x())
+                    }
+                }
+            })
+            .count()
+    }
+
+    /// Moves every item to where it would be if the dragged item was dropped at its target.
+    fn retarget(&self, state: &mut State, layout: Layout<'_>) {
+        let sizes: Vec<Size> = layout
+            .children()
+            .map(|child| child.bounds().size())
+            .collect();
+        // the items wrap where they wrapped in the layout
+        let width = state.wrap;
+
+        // an item dragged in from another list is a gap without an index
+        let mut order: Vec<(Option<usize>, Size)> = sizes
+            .iter()
+            .enumerate()
+            .map(|(i, size)| (Some(i), *size))
+            .collect();
+        if let Some(drag) = state.drag {
+            let item = order.remove(drag.index);
+            if drag.outside {
+                order.push(item);
+            } else {
+                order.insert(drag.target, item);
+            }
+        }
+        if let Some((index, size)) = state.incoming {
+            order.insert(index.min(order.len()), (None, size));
+        }
+
+        let original = arrange(self.direction, &sizes, self.spacing, width);
+        let reordered = arrange(
+            self.direction,
+            &order.iter().map(|(_, size)| *size).collect::<Vec<_>>(),
+            self.spacing,
+            width,
+        );
+
+        for (position, (i, _)) in reordered.into_iter().zip(order) {
+            if let Some(i) = i {
+                state.offsets[i].retarget(position - original[i]);
+            }
+        }
+    }
+
+    /// Makes room for an item dragged in from another list of the same group, returning whether
+    /// the room changed.
+    fn receive(&self, state: &mut State, layout: Layout<'_>) -> bool {
+        let (Some(group), Some(id)) = (&self.group, &self.id) else {
+            return false;
+        };
+
+        let transit = state.transit.as_ref().filter(|transit| {
+            transit.group.as_ref() == Some(group) && transit.list != *id
+        });
+        let in_transit = transit.is_some();
+        let incoming = transit
+            .filter(|transit| layout.bounds().contains(transit.pointer))
+            .map(|transit| {
+                let index = self.insertion(layout, transit.pointer, None);
+                (index, transit.bounds.size())
+            });
+
+        // the index is handed to the list the item was dragged from by the [`Transfers`] around
+        state.hover = incoming.map(|(index, _)| index);
+        if incoming == state.incoming {
+            return false;
+        }
+        state.incoming = incoming;
+
+        if in_transit {
+            self.retarget(state, layout);
+        } else {
+            // the drag is over, and the item will be moved by the application if it was dropped
+            for offset in &mut state.offsets {
+                *offset = Offset::default();
+            }
+        }
+        true
+    }
+
+    /// Follows the drag of the items, publishing their new order once one is dropped.
+    fn follow(
+        &self,
+        state: &mut State,
+        rows: &[Tree],
+        layout: Layout<'_>,
+        shell: &mut iced_core::Shell<'_, Message>,
+    ) {
+        let dragged = rows.iter().position(|row| {
+            matches!(row_state(row).action(), Action::Drag(_, _))
+        });
+
+        match (state.drag, dragged) {
+            (None, None) => return,
+            (None, Some(index)) => {
+                state.drag = Some(Drag {
+                    index,
+                    target: index,
+                    outside: false,
+                    pointer: pointer(row_state(&rows[index])),
+                });
+            }
+            (Some(drag), Some(index)) if drag.index == index => {}
+            (Some(drag), _) => {
+                state.drag = None;
+                state.outgoing = None;
+
+                let hover = self.hover(state).zip(self.id.clone());
+                if !rows
+                    .get(drag.index)
+                    .map(row_state)
+                    .is_some_and(droppable::State::dropped)
+                {
+                    self.retarget(state, layout);
+                    shell.request_redraw();
+                    return;
+                }
+
+                if let Some(((to_list, to_index), from_list)) = hover {
+                    if let Some(on_transfer) = &self.on_transfer {
+                        shell.publish((on_transfer)(Transfer {
+                            from_list,
+                            from_index: drag.index,
+                            to_list,
+                            to_index,
+                        }));
+                    }
+                } else if let Some(on_drop_outside) = &self.on_drop_outside
+                    && !self.covers(state, layout, drag.pointer)
+                {
+                    shell.publish((on_drop_outside)(drag.index));
+                } else if drag.index != drag.target
+                    && let Some(on_reorder) = &self.on_reorder
+                {
+                    shell.publish((on_reorder)(drag.index, drag.target));
+                }
+                // the items will be reordered by the application, so the offsets are no longer
+                // needed
+                for offset in &mut state.offsets {
+                    *offset = Offset::default();
+                }
+                shell.request_redraw();
+                return;
+            }
+        }
+
+        let outside = self.hover(state).is_some();
+        let Some(drag) = &mut state.drag else {
+            return;
+        };
+        let row = row_state(&rows[drag.index]);
+        let pointer = pointer(row);
+
+        drag.pointer = pointer;
+
+        // the item is shared with the other lists of the group by the [`Transfers`] around
+        if self.id.is_some() {
+            state.outgoing = Some((row.overlay_bounds(), pointer));
+        }
+
+        let target = self.insertion(layout, pointer, Some(drag.index));
+        if target != drag.target || outside != drag.outside {
+            drag.target = target;
+            drag.outside = outside;
+            self.retarget(state, layout);
+            shell.request_redraw();
+        }
+    }
+
+    /// Returns whether the item dragged from this list, held at the given point, is over the
+    /// list, another list of its group, or one of the widgets counted as inside.
+    fn covers(&self, state: &State, layout: Layout<'_>, point: Point) -> bool {
+        layout.bounds().contains(point)
+            || state.transit.as_ref().is_some_and(|transit| {
+                Some(&transit.list) == self.id.as_ref() && transit.inside
+            })
+    }
+
+    /// Returns the other list of the group the item dragged from this list is over, and the
+    /// index it would be dropped at.
+    fn hover(&self, state: &State) -> Option<(Id, usize)> {
+        state
+            .transit
+            .as_ref()
+            .filter(|transit| Some(&transit.list) == self.id.as_ref())
+            .and_then(|transit| transit.hover.clone())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sortable<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        tree.state.downcast_mut::<State>().wrap = max.width;
+
+        let mut nodes: Vec<Node> = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(row, tree)| row.layout(tree, renderer, &limits.loose()))
+            .collect();
+        let sizes: Vec<Size> = nodes.iter().map(Node::size).collect();
+        let positions =
+            arrange(self.direction, &sizes, self.spacing, max.width);
+
+        let mut content = Size::ZERO;
+        for (node, position) in nodes.iter_mut().zip(positions) {
+            node.move_to_mut(position);
+            content.width = content.width.max(position.x + node.size().width);
+            content.height =
+                content.height.max(position.y + node.size().height);
+        }
+
+        let size = limits.resolve(self.width, self.height, content);
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        // the dragged item is drawn by its overlay
+        for (i, ((row, tree), layout)) in self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let offset = state
+                .offsets
+                .get(i)
+                .map(|offset| offset.current)
+                .unwrap_or(Vector::ZERO);
+            renderer.with_translation(offset, |renderer| {
+                row.draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            });
+        }
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows().into_iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.rows());
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.rows
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, tree), layout)| {
+                    row.operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_core::Clipboard,
+        shell: &mut iced_core::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        state.offsets.resize(self.rows.len(), Offset::default());
+        state.group.clone_from(&self.group);
+        state.inside.clone_from(&self.inside);
+
+        // items dragged from other lists are followed whatever moves them
+        if state.drag.is_none() && self.receive(state, layout) {
+            shell.request_redraw();
+        }
+
+        // the items handle their own drag, and the events of their content
+        for ((row, tree), layout) in self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            row.update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let mut animating = false;
+            for offset in &mut state.offsets {
+                animating |= offset.tick(*now, self.animation);
+            }
+            if animating {
+                shell.request_redraw();
+            }
+        }
+
+        self.follow(state, &tree.children, layout, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut interactions = self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.mouse_interaction(tree, layout, cursor, viewport, renderer)
+            });
+
+        match state.drag {
+            Some(drag) => interactions.nth(drag.index).unwrap_or_default(),
+            None => interactions.max().unwrap_or_default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((row, tree), layout)| {
+                row.overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sortable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        sortable: Sortable<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(sortable)
+    }
+}
+
+/// A container sharing the items dragged from the [`Sortable`]s inside it with the other lists of
+/// their [`group`].
+///
+/// Lists only know about their own items, so a [`Sortable`] can only receive items from the
+/// lists of its group inside the same [`Transfers`]. The item in transit is kept in the tree of
+/// the [`Transfers`], and handed to the lists inside it on every event.
+///
+/// [`group`]: Sortable::group
+pub struct Transfers<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Transfers<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`Transfers`] around the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Transfers<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> Node {
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<Exchange>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Exchange::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_core::Clipboard,
+        shell: &mut iced_core::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let exchange = tree.state.downcast_mut::<Exchange>();
+        let content = &mut tree.children[0];
+
+        // the lists see the item in transit before they handle the event
+        self.content.as_widget_mut().operate(
+            content,
+            layout,
+            renderer,
+            &mut Hand(&exchange.transit),
+        );
+
+        self.content.as_widget_mut().update(
+            content, event, layout, cursor, renderer, clipboard, shell,
+            viewport,
+        );
+
+        // a list that was removed during the drag can't share its item anymore
+        let mut collect = Collect::default();
+        self.content.as_widget_mut().operate(
+            content,
+            layout,
+            renderer,
+            &mut collect,
+        );
+        let transit = collect.transit();
+
+        // the lists follow a change on the next event
+        if transit != exchange.transit {
+            exchange.transit = transit;
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Transfers<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        transfers: Transfers<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(transfers)
+    }
+}
+
+/// The state of a [`Transfers`].
+#[derive(Default, Debug)]
+struct Exchange {
+    /// The item dragged from one of the lists, if any
+    transit: Option<Transit>,
+}
+
+/// Hands the item in transit to every [`Sortable`].
+struct Hand<'a>(&'a Option<Transit>);
+
+impl Operation for Hand<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Any,
+    ) {
+        if let Some(state) = state.downcast_mut::<State>() {
+            state.transit.clone_from(self.0);
+        }
+    }
+}
+
+/// Collects the item dragged from a [`Sortable`], and where the other lists would drop it.
+#[derive(Default)]
+struct Collect {
+    outgoing: Option<Transit>,
+    /// The Ids of the widgets counting as inside of the list the item was dragged from
+    inside: Vec<Id>,
+    /// The group and Id of the lists an item would be dropped on, and at which index
+    hovers: Vec<(String, Id, usize)>,
+    /// The group and bounds of every list
+    lists: Vec<(Option<String>, Rectangle)>,
+    /// The Id and bounds of every container
+    containers: Vec<(Id, Rectangle)>,
+}
+
+impl Collect {
+    /// Returns the item in transit, over the list it would be dropped on if any.
+    fn transit(self) -> Option<Transit> {
+        let mut transit = self.outgoing?;
+        let group = transit.group.as_ref();
+
+        transit.hover = self
+            .hovers
+            .into_iter()
+            .find(|(other, _, _)| Some(other) == group)
+            .map(|(_, list, index)| (list, index));
+        transit.inside = self
+            .lists
+            .iter()
+            .filter(|(other, _)| group.is_some() && other.as_ref() == group)
+            .map(|(_, bounds)| bounds)
+            .chain(
+                self.containers
+                    .iter()
+                    .filter(|(id, _)| self.inside.contains(id))
+                    .map(|(_, bounds)| bounds),
+            )
+            .any(|bounds| bounds.contains(transit.pointer));
+        Some(transit)
+    }
+}
+
+impl Operation for Collect {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if let Some(id) = id {
+            self.containers.push((id.clone(), bounds));
+        }
+    }
+
+    fn custom(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        state: &mut dyn Any,
+    ) {
+        let (Some(id), Some(state)) = (id, state.downcast_ref::<State>())
+        else {
+            return;
+        };
+        self.lists.push((state.group.clone(), bounds));
+
+        if let Some((bounds, pointer)) = state.outgoing {
+            self.inside.clone_from(&state.inside);
+            self.outgoing = Some(Transit {
+                group: state.group.clone(),
+                list: id.clone(),
+                bounds,
+                pointer,
+                hover: None,
+                inside: false,
+            });
+        }
+        if let (Some(group), Some(index)) = (&state.group, state.hover) {
+            self.hovers.push((group.clone(), id.clone(), index));
+        }
+    }
+}
+
+/// Returns the state of the [`Droppable`] of an item.
+fn row_state(tree: &Tree) -> &droppable::State {
+    tree.state.downcast_ref::<droppable::State>()
+}
+
+/// Returns where the dragged item of a [`Droppable`] is held.
+fn pointer(row: &droppable::State) -> Point {
+    match row.action() {
+        Action::Drag(_, current) => current,
+        _ => row.overlay_bounds().center(),
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+struct State {
+    drag: Option<Drag>,
+    offsets: Vec<Offset>,
+    /// The index and size of an item dragged in from another list
+    incoming: Option<(usize, Size)>,
+    /// The width the items of a grid wrap at
+    wrap: f32,
+    /// The group of the list, read by the [`Transfers`] around it
+    group: Option<String>,
+    /// The Ids of the widgets counting as inside of the list, read by the [`Transfers`] around it
+    inside: Vec<Id>,
+    /// The bounds of the item dragged from this list and where it's held, read by the
+    /// [`Transfers`] around it
+    outgoing: Option<(Rectangle, Point)>,
+    /// The index an item dragged in from another list would be dropped at, read by the
+    /// [`Transfers`] around it
+    hover: Option<usize>,
+    /// The item in transit between the lists of the group, handed down by the [`Transfers`]
+    /// around it
+    transit: Option<Transit>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The index of the dragged item
+    index: usize,
+    /// The index the dragged item would be dropped at
+    target: usize,
+    /// Whether the item is over another list of the same group
+    outside: bool,
+    /// Where the dragged item is held
+    pointer: Point,
+}
+
+/// An item being dragged from a [`Sortable`], shared with the other lists of its group.
+#[derive(Clone, Debug, PartialEq)]
+struct Transit {
+    group: Option<String>,
+    list: Id,
+    /// The bounds of the dragged item
+    bounds: Rectangle,
+    /// Where the dragged item is held
+    pointer: Point,
+    /// The list and index the item would be dropped at
+    hover: Option<(Id, usize)>,
+    /// Whether the item is over a list of the group, or a widget counted as inside of the list
+    /// it was dragged from
+    inside: bool,
+}
+
+/// The animated offset of an item from its position in the layout.
+#[derive(Default, Clone, Copy, Debug)]
+struct Offset {
+    from: Vector,
+    to: Vector,
+    current: Vector,
+    started: Option<Instant>,
+}
+
+impl Offset {
+    fn retarget(&mut self, to: Vector) {
+        if to != self.to {
+            self.from = self.current;
+            self.to = to;
+            self.started = None;
+        }
+    }
+
+    /// Advances the animation, returning whether it's still running.
+    fn tick(&mut self, now: Instant, duration: Duration) -> bool {
+        if self.current == self.to {
+            return false;
+        }
+
+        let started = *self.started.get_or_insert(now);
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.duration_since(started).as_secs_f32() / duration.as_secs_f32())
+                .min(1.0)
+        };
+        // ease out cubic
+        let eased = 1.0 - (1.0 - progress).powi(3);
+
+        self.current = self.from + (self.to - self.from) * eased;
+        if progress >= 1.0 {
+            self.current = self.to;
+        }
+
+        self.current != self.to
+    }
+}
+
+/// Returns the position of items with the given sizes laid out in the given [`Direction`].
+fn arrange(
+    direction: Direction,
+    sizes: &[Size],
+    spacing: f32,
+    max_width: f32,
+) -> Vec<Point> {
+    let mut position = Point::ORIGIN;
+    let mut row_height: f32 = 0.0;
+
+    sizes
+        .iter()
+        .map(|size| match direction {
+            Direction::Vertical => {
+                let item = position;
+                position.y += size.height + spacing;
+                item
+            }
+            Direction::Horizontal => {
+                let item = position;
+                position.x += size.width + spacing;
+                item
+            }
+            Direction::Grid => {
+                if position.x > 0.0 && position.x + size.width > max_width {
+                    position.x = 0.0;
+                    position.y += row_height + spacing;
+                    row_height = 0.0;
+                }
+                let item = position;
+                position.x += size.width + spacing;
+                row_height = row_height.max(size.height);
+                item
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,133 @@
+//! Checks which zones each collision strategy accepts, and the order it ranks them in.
+use iced_core::{Point, Rectangle, Size};
+use iced_drop::collision::{
+    ClosestCenter, ClosestCorners, Collision, LargestOverlap, PointerWithin,
+    RectIntersection, rank,
+};
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+/// The keys of the zones ranked by the strategy, from the best to the worst target.
+fn keys(
+    collision: &impl Collision,
+    pointer: Point,
+    dragged: Rectangle,
+    zones: &[(char, Rectangle)],
+) -> Vec<char> {
+    rank(collision, pointer, dragged, zones.to_vec())
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect()
+}
+
+#[test]
+fn pointer_within_prefers_the_innermost_zone() {
+    let zones = [
+        ('a', rect(0.0, 0.0, 100.0, 100.0)),
+        ('b', rect(10.0, 10.0, 20.0, 20.0)),
+        ('c', rect(200.0, 200.0, 10.0, 10.0)),
+    ];
+    let pointer = Point::new(15.0, 15.0);
+    let dragged = rect(0.0, 0.0, 300.0, 300.0);
+
+    assert_eq!(keys(&PointerWithin, pointer, dragged, &zones), ['b', 'a']);
+    assert_eq!(
+        PointerWithin.collide(Point::new(150.0, 150.0), dragged, zones[0].1),
+        None
+    );
+}
+
+#[test]
+fn rect_intersection_prefers_the_most_similar_zone() {
+    let dragged = rect(0.0, 0.0, 20.0, 20.0);
+    let zones = [
+        // contains the dragged bounds, but is much larger
+        ('a', rect(0.0, 0.0, 100.0, 100.0)),
+        // the same size, half covered
+        ('b', rect(10.0, 0.0, 20.0, 20.0)),
+        // the same bounds
+        ('c', dragged),
+        ('d', rect(50.0, 50.0, 20.0, 20.0)),
+    ];
+    let pointer = dragged.center();
+
+    assert_eq!(
+        keys(&RectIntersection, pointer, dragged, &zones),
+        ['c', 'b', 'a']
+    );
+    assert_eq!(RectIntersection.collide(pointer, dragged, zones[3].1), None);
+}
+
+#[test]
+fn largest_overlap_prefers_the_largest_intersection() {
+    let dragged = rect(0.0, 0.0, 20.0, 20.0);
+    let zones = [
+        ('a', rect(15.0, 0.0, 20.0, 20.0)),
+        ('b', rect(0.0, 0.0, 100.0, 100.0)),
+        ('c', rect(10.0, 0.0, 20.0, 20.0)),
+        ('d', rect(-50.0, -50.0, 10.0, 10.0)),
+    ];
+    let pointer = dragged.center();
+
+    assert_eq!(
+        keys(&LargestOverlap, pointer, dragged, &zones),
+        ['b', 'c', 'a']
+    );
+    assert_eq!(LargestOverlap.collide(pointer, dragged, zones[3].1), None);
+}
+
+#[test]
+fn closest_center_ranks_every_zone_by_distance() {
+    let dragged = rect(0.0, 0.0, 10.0, 10.0);
+    let zones = [
+        ('a', rect(100.0, 0.0, 10.0, 10.0)),
+        ('b', rect(0.0, 20.0, 10.0, 10.0)),
+        ('c', rect(-500.0, -500.0, 10.0, 10.0)),
+    ];
+
+    assert_eq!(
+        keys(&ClosestCenter, dragged.center(), dragged, &zones),
+        ['b', 'a', 'c']
+    );
+}
+
+#[test]
+fn closest_corners_prefers_zones_of_a_similar_size() {
+    let dragged = rect(0.0, 0.0, 10.0, 10.0);
+    let zones = [
+        // shares its center with the dragged bounds, but is much larger
+        ('a', rect(-45.0, -45.0, 100.0, 100.0)),
+        ('b', rect(5.0, 5.0, 10.0, 10.0)),
+    ];
+
+    assert_eq!(
+        keys(&ClosestCorners, dragged.center(), dragged, &zones),
+        ['b', 'a']
+    );
+}
+
+#[test]
+fn rank_keeps_the_order_of_equal_ranks() {
+    let dragged = rect(0.0, 0.0, 10.0, 10.0);
+    let zones = [
+        ('a', rect(20.0, 0.0, 10.0, 10.0)),
+        ('b', rect(0.0, 20.0, 10.0, 10.0)),
+        ('c', rect(-20.0, 0.0, 10.0, 10.0)),
+    ];
+
+    assert_eq!(
+        keys(&ClosestCenter, dragged.center(), dragged, &zones),
+        ['a', 'b', 'c']
+    );
+}
+
+#[test]
+fn rank_drops_every_zone_when_nothing_collides() {
+    let never = |_: Point, _: Rectangle, _: Rectangle| None;
+    let zones = [('a', rect(0.0, 0.0, 10.0, 10.0))];
+
+    assert!(keys(&never, Point::ORIGIN, zones[0].1, &zones).is_empty());
+    assert!(keys(&PointerWithin, Point::ORIGIN, zones[0].1, &[]).is_empty());
+}
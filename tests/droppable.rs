@@ -0,0 +1,119 @@
+//! Drops droppables with a reset timeout, and checks how long they keep their drag size.
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::{self, Cursor};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::Tree;
+use iced_core::{
+    Event, Layout, Point, Rectangle, Shell, Size, Vector, Widget, clipboard,
+    window,
+};
+use iced_drop::widget::droppable::Droppable;
+use iced_widget::{Space, Theme};
+
+const DRAG_SIZE: Size = Size::new(100.0, 5.0);
+
+/// A droppable of 100x20 shrinking to [`DRAG_SIZE`] while it's dragged.
+struct Item {
+    droppable: Droppable<'static, (), Theme, ()>,
+    tree: Tree,
+    position: Point,
+}
+
+impl Item {
+    fn new(reset_timeout: Duration) -> Self {
+        let droppable = Droppable::new(Space::new().width(100).height(20))
+            .on_drop(|_, _| ())
+            .drag_size(DRAG_SIZE)
+            .reset_timeout(reset_timeout);
+        let tree = Tree::new(&droppable as &dyn Widget<(), Theme, ()>);
+
+        Self {
+            droppable,
+            tree,
+            position: Point::ORIGIN,
+        }
+    }
+
+    fn layout(&mut self) -> Node {
+        self.droppable
+            .layout(
+                &mut self.tree,
+                &(),
+                &Limits::new(Size::ZERO, Size::INFINITE),
+            )
+            .move_to(self.position)
+    }
+
+    fn update(&mut self, event: Event, cursor: Point) {
+        let node = self.layout();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        self.droppable.update(
+            &mut self.tree,
+            &event,
+            Layout::new(&node),
+            Cursor::Available(cursor),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+    }
+
+    /// Drags the droppable down and drops it.
+    fn drag_and_drop(&mut self) {
+        let start = Point::new(50.0, 10.0);
+        let end = start + Vector::new(0.0, 50.0);
+
+        self.update(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            start,
+        );
+        self.update(
+            Event::Mouse(mouse::Event::CursorMoved { position: end }),
+            end,
+        );
+        self.update(
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            end,
+        );
+    }
+
+    fn redraw(&mut self, now: Instant) {
+        self.update(
+            Event::Window(window::Event::RedrawRequested(now)),
+            Point::ORIGIN,
+        );
+    }
+
+    fn size(&mut self) -> Size {
+        self.layout().size()
+    }
+}
+
+#[test]
+fn the_drag_size_is_kept_until_the_droppable_moves() {
+    // too long to end, so the droppable waits until it's moved
+    let mut item = Item::new(Duration::MAX);
+
+    item.drag_and_drop();
+    item.redraw(Instant::now() + Duration::from_secs(3600));
+    assert_eq!(item.size(), DRAG_SIZE);
+
+    item.position = Point::new(0.0, 50.0);
+    item.redraw(Instant::now());
+    assert_eq!(item.size(), Size::new(100.0, 20.0));
+}
+
+#[test]
+fn the_drag_size_is_reset_once_the_timeout_expires() {
+    let mut item = Item::new(Duration::from_millis(100));
+
+    item.drag_and_drop();
+    item.redraw(Instant::now());
+    assert_eq!(item.size(), DRAG_SIZE);
+
+    item.redraw(Instant::now() + Duration::from_secs(1));
+    assert_eq!(item.size(), Size::new(100.0, 20.0));
+}
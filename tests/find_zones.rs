@@ -0,0 +1,324 @@
+//! Drives the zone finders through the widget tree by hand, and checks the zones they find.
+use iced_core::layout::Node;
+use iced_core::widget::operation::scrollable::{
+    AbsoluteOffset, RelativeOffset,
+};
+use iced_core::widget::operation::{self, Outcome, Scrollable};
+use iced_core::widget::{Id, Operation, Tree};
+use iced_core::{Layout, Point, Rectangle, Size, Vector, Widget};
+use iced_drop::collision::ClosestCenter;
+use iced_drop::widget::drop_zone::DropZone;
+use iced_drop::widget::operation::drop::{
+    TypedZone, Zone, find_ranked_zones, find_typed_zones, find_zones,
+    snapshot_zones,
+};
+use iced_widget::{Space, Theme};
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+/// A scrollable that can't be scrolled by the operations.
+struct Fixed;
+
+impl Scrollable for Fixed {
+    fn snap_to(&mut self, _offset: RelativeOffset<Option<f32>>) {}
+
+    fn scroll_to(&mut self, _offset: AbsoluteOffset<Option<f32>>) {}
+
+    fn scroll_by(
+        &mut self,
+        _offset: AbsoluteOffset,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+    ) {
+    }
+}
+
+/// Operates a scrollable with the given bounds and translation, around the given content.
+fn scrollable<T>(
+    operation: &mut dyn Operation<T>,
+    bounds: Rectangle,
+    translation: Vector,
+    content: impl FnOnce(&mut dyn Operation<T>),
+) {
+    operation.scrollable(None, bounds, bounds, translation, &mut Fixed);
+    traverse(operation, content);
+}
+
+/// Operates a container with the given id and bounds, around the given content.
+fn container<T>(
+    operation: &mut dyn Operation<T>,
+    id: &'static str,
+    bounds: Rectangle,
+    content: impl FnOnce(&mut dyn Operation<T>),
+) {
+    operation.container(Some(&Id::new(id)), bounds);
+    traverse(operation, content);
+}
+
+/// Operates the given content one level deeper in the widget tree.
+fn traverse<T>(
+    operation: &mut dyn Operation<T>,
+    content: impl FnOnce(&mut dyn Operation<T>),
+) {
+    let mut content = Some(content);
+    operation.traverse(&mut |operation| {
+        if let Some(content) = content.take() {
+            content(operation);
+        }
+    });
+}
+
+/// Operates a drop zone with the given key and bounds.
+fn zone<T: 'static>(
+    operation: &mut dyn Operation<T>,
+    key: char,
+    bounds: Rectangle,
+) {
+    let mut zone: DropZone<'_, char, (), Theme, ()> =
+        DropZone::new(key, Space::new());
+    let mut tree = Tree::new(&zone as &dyn Widget<(), Theme, ()>);
+    let node = Node::new(bounds.size()).move_to(bounds.position());
+
+    zone.operate(
+        &mut tree,
+        Layout::new(&node),
+        &(),
+        &mut operation::black_box(operation),
+    );
+}
+
+fn finish<T>(operation: &dyn Operation<T>) -> T {
+    match operation.finish() {
+        Outcome::Some(output) => output,
+        _ => panic!("the operation didn't finish"),
+    }
+}
+
+/// Finds every zone operated by `tree`.
+fn zones(
+    depth: Option<usize>,
+    tree: impl FnOnce(&mut dyn Operation<Vec<Zone>>),
+) -> Vec<Zone> {
+    let mut find = find_zones(|_| true, None, depth);
+    tree(&mut find);
+    finish(&find)
+}
+
+/// The id, bounds and visible bounds of a zone.
+fn placement(zone: &Zone) -> (Id, Rectangle, Rectangle) {
+    (zone.id.clone(), zone.bounds, zone.visible)
+}
+
+/// The id, depth and parent of a zone.
+fn ancestry(zone: &Zone) -> (Id, usize, Option<Id>) {
+    (zone.id.clone(), zone.depth(), zone.parent().cloned())
+}
+
+/// Operates a zone scrolled halfway out of view, whose visible part is the closest to the
+/// dragged bounds, and a smaller zone fully in view whose center is closer to its full center.
+fn clipped_zones<T: 'static>(operation: &mut dyn Operation<T>) {
+    scrollable(operation, rect(0.0, 0.0, 100.0, 100.0), Vector::ZERO, |o| {
+        zone(o, 'a', rect(0.0, 60.0, 100.0, 200.0));
+        zone(o, 'b', rect(0.0, 0.0, 100.0, 40.0));
+    });
+}
+
+#[test]
+fn zones_are_ranked_on_their_visible_bounds() {
+    let dragged = rect(0.0, 50.0, 100.0, 40.0);
+    let mut find = find_ranked_zones(
+        ClosestCenter,
+        dragged.center(),
+        dragged,
+        None::<Vec<char>>,
+        None,
+    );
+    clipped_zones(&mut find);
+
+    // the zones are still reported with their full bounds, along with their visible bounds
+    let zones: Vec<_> = finish(&find)
+        .into_iter()
+        .map(|zone| (zone.key, zone.bounds, zone.visible))
+        .collect();
+    assert_eq!(
+        zones,
+        [
+            (
+                'a',
+                rect(0.0, 60.0, 100.0, 200.0),
+                rect(0.0, 60.0, 100.0, 40.0)
+            ),
+            (
+                'b',
+                rect(0.0, 0.0, 100.0, 40.0),
+                rect(0.0, 0.0, 100.0, 40.0)
+            )
+        ]
+    );
+}
+
+#[test]
+fn typed_zones_keep_their_visible_bounds() {
+    let mut find = find_typed_zones(|_| true, None::<Vec<char>>, None);
+    clipped_zones(&mut find);
+
+    assert_eq!(
+        finish(&find)[0],
+        TypedZone {
+            key: 'a',
+            bounds: rect(0.0, 60.0, 100.0, 200.0),
+            visible: rect(0.0, 60.0, 100.0, 40.0),
+            path: vec!['a'],
+        }
+    );
+}
+
+#[test]
+fn snapshots_rank_zones_on_their_visible_bounds() {
+    let dragged = rect(0.0, 50.0, 100.0, 40.0);
+    let mut snapshot = snapshot_zones::<char, _>((), None, None);
+    clipped_zones(&mut snapshot);
+
+    let keys: Vec<char> = finish(&snapshot)
+        .hit(&ClosestCenter, dragged.center(), dragged, None)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, ['a', 'b']);
+}
+
+#[test]
+fn nested_scrollables_offset_and_clip_their_content() {
+    let found = zones(None, |o| {
+        scrollable(
+            o,
+            rect(0.0, 0.0, 200.0, 200.0),
+            Vector::new(0.0, 50.0),
+            |o| {
+                // laid out below the outer viewport, but scrolled into it
+                scrollable(
+                    o,
+                    rect(0.0, 100.0, 200.0, 100.0),
+                    Vector::new(0.0, 20.0),
+                    |o| {
+                        container(
+                            o,
+                            "inner",
+                            rect(0.0, 150.0, 200.0, 100.0),
+                            |_| {},
+                        )
+                    },
+                );
+            },
+        );
+    });
+
+    assert_eq!(
+        found.iter().map(placement).collect::<Vec<_>>(),
+        [(
+            Id::new("inner"),
+            rect(0.0, 80.0, 200.0, 100.0),
+            rect(0.0, 80.0, 200.0, 70.0)
+        )]
+    );
+}
+
+#[test]
+fn zones_scrolled_out_of_view_are_not_found() {
+    let found = zones(None, |o| {
+        scrollable(
+            o,
+            rect(0.0, 0.0, 100.0, 100.0),
+            Vector::new(0.0, 300.0),
+            |o| {
+                container(o, "hidden", rect(0.0, 0.0, 100.0, 100.0), |_| {});
+                container(o, "shown", rect(0.0, 300.0, 100.0, 100.0), |_| {});
+            },
+        );
+    });
+
+    assert_eq!(
+        found.iter().map(placement).collect::<Vec<_>>(),
+        [(
+            Id::new("shown"),
+            rect(0.0, 0.0, 100.0, 100.0),
+            rect(0.0, 0.0, 100.0, 100.0)
+        )]
+    );
+}
+
+#[test]
+fn siblings_of_a_scrolled_branch_are_not_scrolled() {
+    let found = zones(None, |o| {
+        traverse(o, |o| {
+            scrollable(
+                o,
+                rect(0.0, 0.0, 100.0, 100.0),
+                Vector::new(0.0, 50.0),
+                |o| {
+                    container(
+                        o,
+                        "scrolled",
+                        rect(0.0, 50.0, 100.0, 100.0),
+                        |_| {},
+                    );
+                },
+            );
+            container(o, "sibling", rect(0.0, 300.0, 100.0, 100.0), |_| {});
+        });
+    });
+
+    assert_eq!(
+        found.iter().map(placement).collect::<Vec<_>>(),
+        [
+            (
+                Id::new("scrolled"),
+                rect(0.0, 0.0, 100.0, 100.0),
+                rect(0.0, 0.0, 100.0, 100.0)
+            ),
+            (
+                Id::new("sibling"),
+                rect(0.0, 300.0, 100.0, 100.0),
+                rect(0.0, 300.0, 100.0, 100.0)
+            )
+        ]
+    );
+}
+
+#[test]
+fn depth_is_tracked_per_branch() {
+    let tree = |o: &mut dyn Operation<Vec<Zone>>| {
+        container(o, "a", rect(0.0, 0.0, 300.0, 300.0), |o| {
+            container(o, "b", rect(0.0, 0.0, 100.0, 100.0), |o| {
+                container(o, "c", rect(0.0, 0.0, 50.0, 50.0), |_| {});
+            });
+            container(o, "d", rect(100.0, 0.0, 100.0, 100.0), |_| {});
+        });
+    };
+
+    let found = zones(None, tree);
+    assert_eq!(found[2].path, [Id::new("a"), Id::new("b"), Id::new("c")]);
+    assert_eq!(
+        found.iter().map(ancestry).collect::<Vec<_>>(),
+        [
+            (Id::new("a"), 0, None),
+            (Id::new("b"), 1, Some(Id::new("a"))),
+            (Id::new("c"), 2, Some(Id::new("b"))),
+            (Id::new("d"), 1, Some(Id::new("a")))
+        ]
+    );
+    // the sibling of a branch cut at the maximum depth is still found
+    assert_eq!(
+        zones(Some(2), tree)
+            .iter()
+            .map(ancestry)
+            .collect::<Vec<_>>(),
+        [
+            (Id::new("a"), 0, None),
+            (Id::new("b"), 1, Some(Id::new("a"))),
+            (Id::new("d"), 1, Some(Id::new("a")))
+        ]
+    );
+}
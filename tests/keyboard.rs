@@ -0,0 +1,127 @@
+//! Drags a droppable with the keyboard, and checks the zones it's moved between.
+use iced_core::keyboard::{self, key};
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::{self, Cursor};
+use iced_core::widget::operation::Outcome;
+use iced_core::widget::{Id, Operation, Tree};
+use iced_core::{
+    Event, Layout, Point, Rectangle, Shell, Size, Widget, clipboard,
+};
+use iced_drop::widget::droppable::Droppable;
+use iced_drop::widget::operation::drop::load_keyboard_targets;
+use iced_widget::{Space, Theme};
+
+/// A droppable of 100x20 at the origin, publishing where it's dragged to.
+struct Item {
+    droppable: Droppable<'static, Point, Theme, ()>,
+    tree: Tree,
+    node: Node,
+}
+
+impl Item {
+    fn new() -> Self {
+        let mut droppable = Droppable::new(Space::new().width(100).height(20))
+            .id(Id::new("item"))
+            .on_drag(|_, event| event.point)
+            .on_drop(|_, event| event.point);
+        let mut tree = Tree::new(&droppable as &dyn Widget<Point, Theme, ()>);
+        let node = droppable.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::INFINITE),
+        );
+
+        Self {
+            droppable,
+            tree,
+            node,
+        }
+    }
+
+    fn update(&mut self, event: Event) -> Vec<Point> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        self.droppable.update(
+            &mut self.tree,
+            &event,
+            Layout::new(&self.node),
+            Cursor::Available(Point::new(50.0, 10.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        drop(shell);
+        messages
+    }
+
+    fn press(&mut self, key: key::Named) -> Vec<Point> {
+        self.update(Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modified_key: keyboard::Key::Named(key),
+            physical_key: key::Physical::Unidentified(
+                key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            repeat: false,
+        }))
+    }
+
+    /// Loads the zones at the given centers as the keyboard targets of the droppable.
+    fn load_targets(&mut self, centers: &[Point]) {
+        let mut load = load_keyboard_targets(Id::new("item"), None, None);
+        load.traverse(&mut |operation| {
+            for (i, center) in centers.iter().enumerate() {
+                operation.container(
+                    Some(&Id::from(format!("zone{i}"))),
+                    Rectangle::new(*center, Size::ZERO).expand(10.0),
+                );
+            }
+        });
+
+        let Outcome::Chain(mut set) = load.finish() else {
+            panic!("the targets weren't found");
+        };
+        self.droppable.operate(
+            &mut self.tree,
+            Layout::new(&self.node),
+            &(),
+            set.as_mut(),
+        );
+    }
+}
+
+#[test]
+fn reloading_the_targets_keeps_the_current_one() {
+    let zones = [
+        Point::new(50.0, 100.0),
+        Point::new(50.0, 200.0),
+        Point::new(50.0, 300.0),
+    ];
+    let mut item = Item::new();
+
+    // focus the droppable by clicking it, and pick it up
+    item.update(Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    )));
+    item.update(Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    )));
+    item.press(key::Named::Space);
+
+    // the targets are reloaded on every drag message, like an application reloading them
+    // whenever it's told the droppable moved
+    let mut visited = vec![];
+    for _ in 0..4 {
+        item.load_targets(&zones);
+        visited.extend(item.press(key::Named::Tab));
+    }
+    assert_eq!(visited, [zones[0], zones[1], zones[2], zones[0]]);
+
+    // a target found at another index is followed to it
+    item.load_targets(&[zones[1], zones[0], zones[2]]);
+    assert_eq!(item.press(key::Named::Tab), [zones[2]]);
+}
@@ -0,0 +1,154 @@
+//! Rebuilds keyed columns in another order, and checks which states their children end up with.
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::{self, Cursor};
+use iced_core::renderer::Style;
+use iced_core::widget::Tree;
+use iced_core::widget::tree::{self, Tag};
+use iced_core::{
+    Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    clipboard,
+};
+use iced_drop::widget::droppable::{Action, Droppable, State};
+use iced_drop::widget::keyed::Column;
+use iced_widget::{Space, Theme};
+
+/// A widget whose state is the label it had when its state was created.
+struct Marker(u32);
+
+impl Widget<(), Theme, ()> for Marker {
+    fn tag(&self) -> Tag {
+        Tag::of::<u32>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(self.0)
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(10.0), Length::Fixed(10.0))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &(),
+        _limits: &Limits,
+    ) -> Node {
+        Node::new(Size::new(10.0, 10.0))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut (),
+        _theme: &Theme,
+        _style: &Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+/// A keyed column of markers with the given keys and labels.
+fn markers(children: &[(char, u32)]) -> Column<'static, char, (), Theme, ()> {
+    Column::new(
+        children
+            .iter()
+            .map(|&(key, label)| (key, Element::new(Marker(label)))),
+    )
+}
+
+/// The labels the markers of the tree had when their states were created.
+fn labels(tree: &Tree) -> Vec<u32> {
+    tree.children
+        .iter()
+        .map(|child| *child.state.downcast_ref::<u32>())
+        .collect()
+}
+
+fn diff(tree: &mut Tree, column: &Column<'static, char, (), Theme, ()>) {
+    tree.diff(column as &dyn Widget<(), Theme, ()>);
+}
+
+#[test]
+fn reordered_children_keep_their_state() {
+    let mut tree =
+        Tree::new(&markers(&[('a', 1), ('b', 2), ('c', 3)])
+            as &dyn Widget<(), Theme, ()>);
+
+    diff(&mut tree, &markers(&[('c', 0), ('a', 0), ('b', 0)]));
+    assert_eq!(labels(&tree), [3, 1, 2]);
+}
+
+#[test]
+fn removed_children_drop_their_state() {
+    let mut tree =
+        Tree::new(&markers(&[('a', 1), ('b', 2), ('c', 3)])
+            as &dyn Widget<(), Theme, ()>);
+
+    diff(&mut tree, &markers(&[('c', 0), ('a', 0)]));
+    assert_eq!(labels(&tree), [3, 1]);
+
+    // the key is back, but its old state is gone
+    diff(&mut tree, &markers(&[('a', 4), ('b', 5), ('c', 6)]));
+    assert_eq!(labels(&tree), [1, 5, 3]);
+}
+
+#[test]
+fn duplicate_keys_keep_their_order() {
+    let mut tree =
+        Tree::new(&markers(&[('a', 1), ('b', 2), ('a', 3)])
+            as &dyn Widget<(), Theme, ()>);
+
+    diff(&mut tree, &markers(&[('b', 0), ('a', 0), ('a', 0)]));
+    assert_eq!(labels(&tree), [2, 1, 3]);
+
+    // the last child of a key is the one removed
+    diff(&mut tree, &markers(&[('a', 0), ('b', 0)]));
+    assert_eq!(labels(&tree), [1, 2]);
+}
+
+#[test]
+fn droppables_keep_their_drag_across_reorders() {
+    let column = |keys: [char; 3]| {
+        Column::new(keys.map(|key| {
+            let droppable: Droppable<'static, (), Theme, ()> =
+                Droppable::new(Space::new().width(100).height(20))
+                    .on_drop(|_, _| ());
+            (key, Element::from(droppable))
+        }))
+    };
+
+    let mut first = column(['a', 'b', 'c']);
+    let mut tree = Tree::new(&first as &dyn Widget<(), Theme, ()>);
+    let node =
+        first.layout(&mut tree, &(), &Limits::new(Size::ZERO, Size::INFINITE));
+
+    // press on the first droppable
+    let mut messages = Vec::new();
+    let mut shell = Shell::new(&mut messages);
+    let pressed = Point::new(50.0, 10.0);
+    first.update(
+        &mut tree,
+        &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Layout::new(&node),
+        Cursor::Available(pressed),
+        &(),
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::with_size(Size::INFINITE),
+    );
+
+    tree.diff(&column(['b', 'c', 'a']) as &dyn Widget<(), Theme, ()>);
+
+    let actions: Vec<Action> = tree
+        .children
+        .iter()
+        .map(|child| child.state.downcast_ref::<State>().action())
+        .collect();
+    assert_eq!(
+        actions,
+        [Action::None, Action::None, Action::Select(pressed)]
+    );
+}
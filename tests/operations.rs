@@ -0,0 +1,144 @@
+//! Controls a droppable with the drag operations, and checks its status and the messages it
+//! publishes.
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::Cursor;
+use iced_core::time::Instant;
+use iced_core::widget::operation::{self, Outcome};
+use iced_core::widget::{Id, Operation, Tree};
+use iced_core::{
+    Event, Layout, Point, Rectangle, Shell, Size, Widget, clipboard, window,
+};
+use iced_drop::widget::droppable::{Action, Droppable, Status};
+use iced_drop::widget::operation::drag::{
+    cancel_drag, drag_status, reset_drag, start_drag,
+};
+use iced_widget::{Space, Theme};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Drag(Point),
+    Drop(Point),
+    Cancel,
+}
+
+/// A droppable of 100x20 at the origin with the Id "item".
+struct Item {
+    droppable: Droppable<'static, Message, Theme, ()>,
+    tree: Tree,
+    node: Node,
+}
+
+impl Item {
+    fn new() -> Self {
+        let mut droppable = Droppable::new(Space::new().width(100).height(20))
+            .id(Id::new("item"))
+            .on_drop(|_, event| Message::Drop(event.point))
+            .on_drag(|_, event| Message::Drag(event.point))
+            .on_cancel(Message::Cancel);
+        let mut tree = Tree::new(&droppable as &dyn Widget<Message, Theme, ()>);
+        let node = droppable.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::INFINITE),
+        );
+
+        Self {
+            droppable,
+            tree,
+            node,
+        }
+    }
+
+    fn operate<T>(&mut self, mut operation: impl Operation<T>) -> Outcome<T> {
+        self.droppable.operate(
+            &mut self.tree,
+            Layout::new(&self.node),
+            &(),
+            &mut operation::black_box(&mut operation),
+        );
+        operation.finish()
+    }
+
+    fn status(&mut self, id: &'static str) -> Option<(Action, Status)> {
+        match self.operate(drag_status(Id::new(id))) {
+            Outcome::Some(status) => status,
+            _ => panic!("the status wasn't output"),
+        }
+    }
+
+    /// Redraws the droppable, like the drag tasks ask for, returning the published messages.
+    fn redraw(&mut self) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        self.droppable.update(
+            &mut self.tree,
+            &Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Layout::new(&self.node),
+            Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        drop(shell);
+        messages
+    }
+}
+
+#[test]
+fn started_drags_apply_right_away() {
+    let mut item = Item::new();
+    let point = Point::new(50.0, 10.0);
+
+    item.operate(start_drag(Id::new("item"), point));
+    assert_eq!(
+        item.status("item").map(|(action, _)| action),
+        Some(Action::Drag(point, point))
+    );
+    assert_eq!(item.redraw(), [Message::Drag(point)]);
+}
+
+#[test]
+fn cancelled_drags_apply_right_away() {
+    let mut item = Item::new();
+    let point = Point::new(50.0, 10.0);
+
+    item.operate(start_drag(Id::new("item"), point));
+    item.redraw();
+
+    item.operate(cancel_drag(Id::new("item")));
+    assert_eq!(
+        item.status("item").map(|(action, _)| action),
+        Some(Action::None)
+    );
+    assert_eq!(item.redraw(), [Message::Cancel]);
+
+    // nothing is dragged anymore
+    item.operate(cancel_drag(Id::new("item")));
+    assert!(item.redraw().is_empty());
+}
+
+#[test]
+fn reset_drags_publish_nothing() {
+    let mut item = Item::new();
+    let point = Point::new(50.0, 10.0);
+
+    item.operate(start_drag(Id::new("item"), point));
+    item.redraw();
+
+    item.operate(reset_drag(Id::new("item")));
+    assert_eq!(
+        item.status("item").map(|(action, _)| action),
+        Some(Action::None)
+    );
+    assert!(item.redraw().is_empty());
+}
+
+#[test]
+fn the_status_of_unknown_droppables_is_none() {
+    let mut item = Item::new();
+
+    assert_eq!(item.status("item"), Some((Action::None, Status::Active)));
+    assert_eq!(item.status("other"), None);
+}
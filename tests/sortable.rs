@@ -0,0 +1,442 @@
+//! Drives sortable lists with events, and checks the messages they publish and the offsets they
+//! draw their items at.
+use iced_core::layout::{Limits, Node};
+use iced_core::mouse::{self, Cursor};
+use iced_core::renderer::{Quad, Style};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{Id, Tree};
+use iced_core::{
+    Background, Element, Event, Layout, Length, Point, Rectangle, Renderer,
+    Shell, Size, Transformation, Vector, Widget, clipboard, image, window,
+};
+use iced_drop::widget::keyed::Column;
+use iced_drop::widget::sortable::{Direction, Sortable, Transfer, Transfers};
+use iced_widget::{Container, Space, Theme};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Reorder(usize, usize),
+    Transfer(Transfer),
+    DropOutside(usize),
+}
+
+/// A renderer recording the translation of everything it draws.
+#[derive(Default)]
+struct Recorder {
+    translations: Vec<Vector>,
+}
+
+impl Renderer for Recorder {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, transformation: Transformation) {
+        self.translations.push(transformation.translation());
+    }
+
+    fn end_transformation(&mut self) {}
+
+    fn fill_quad(&mut self, _quad: Quad, _background: impl Into<Background>) {}
+
+    fn reset(&mut self, _new_bounds: Rectangle) {}
+
+    fn allocate_image(
+        &mut self,
+        _handle: &image::Handle,
+        _callback: impl FnOnce(Result<image::Allocation, image::Error>)
+        + Send
+        + 'static,
+    ) {
+    }
+}
+
+/// A sortable list of items of the given sizes, publishing every message.
+fn items(sizes: &[Size]) -> Sortable<'static, Message, Theme, Recorder> {
+    Sortable::new(sizes.iter().map(|size| {
+        Element::from(Space::new().width(size.width).height(size.height))
+    }))
+    .animation(Duration::ZERO)
+    .on_reorder(Message::Reorder)
+    .on_transfer(Message::Transfer)
+    .on_drop_outside(Message::DropOutside)
+}
+
+struct List {
+    sortable: Sortable<'static, Message, Theme, Recorder>,
+    tree: Tree,
+    node: Node,
+}
+
+impl List {
+    /// A vertical list of three items of 100x20, in the group "items".
+    fn new(id: &'static str, position: Point) -> Self {
+        Self::laid_out(
+            items(&[Size::new(100.0, 20.0); 3])
+                .id(Id::new(id))
+                .group("items"),
+            position,
+            Size::INFINITE,
+        )
+    }
+
+    /// Lays out the sortable at the given position, in at most the given size.
+    fn laid_out(
+        mut sortable: Sortable<'static, Message, Theme, Recorder>,
+        position: Point,
+        max: Size,
+    ) -> Self {
+        let mut tree =
+            Tree::new(&sortable as &dyn Widget<Message, Theme, Recorder>);
+        let node = sortable
+            .layout(
+                &mut tree,
+                &Recorder::default(),
+                &Limits::new(Size::ZERO, max),
+            )
+            .move_to(position);
+
+        Self {
+            sortable,
+            tree,
+            node,
+        }
+    }
+
+    /// Handles the event with the cursor at the given position, returning the published
+    /// messages.
+    fn update(&mut self, event: Event, cursor: Point) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        self.sortable.update(
+            &mut self.tree,
+            &event,
+            Layout::new(&self.node),
+            Cursor::Available(cursor),
+            &Recorder::default(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        drop(shell);
+        messages
+    }
+
+    /// Returns the offsets the items are drawn at.
+    fn offsets(&self) -> Vec<Vector> {
+        let mut recorder = Recorder::default();
+
+        self.sortable.draw(
+            &self.tree,
+            &mut recorder,
+            &Theme::Light,
+            &Style::default(),
+            Layout::new(&self.node),
+            Cursor::Unavailable,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        recorder.translations
+    }
+}
+
+fn press() -> Event {
+    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+}
+
+fn release() -> Event {
+    Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+}
+
+fn move_to(position: Point) -> Event {
+    Event::Mouse(mouse::Event::CursorMoved { position })
+}
+
+fn redraw() -> Event {
+    Event::Window(window::Event::RedrawRequested(Instant::now()))
+}
+
+/// Lists of three items of 100x20 laid out in a column 40 apart, sharing their items through a
+/// [`Transfers`], and rebuilt on every event like the view of an application.
+///
+/// The lists are followed by a widget of 100x60 with the Id "adder", like the input adding items
+/// under a list.
+struct Board {
+    /// The Id and group of every list
+    lists: Vec<(&'static str, &'static str)>,
+    /// The widgets counting as inside of the lists
+    inside: Vec<Id>,
+    tree: Tree,
+}
+
+impl Board {
+    fn new(lists: &[(&'static str, &'static str)]) -> Self {
+        let mut board = Self {
+            lists: lists.to_vec(),
+            inside: Vec::new(),
+            tree: Tree::empty(),
+        };
+        board.tree = Tree::new(&board.view() as &dyn Widget<_, _, _>);
+        board
+    }
+
+    fn view(&self) -> Transfers<'static, Message, Theme, Recorder> {
+        Transfers::new(
+            Column::new(self.lists.iter().map(|&(id, group)| {
+                let list = items(&[Size::new(100.0, 20.0); 3])
+                    .id(Id::new(id))
+                    .group(group)
+                    .inside(self.inside.clone());
+                (id, Element::from(list))
+            }))
+            .push(
+                "adder",
+                Container::new(Space::new().width(100).height(60))
+                    .id(Id::new("adder")),
+            )
+            .spacing(40),
+        )
+    }
+
+    /// Rebuilds the lists, and lays them out like the application would before handling events.
+    fn build(
+        &mut self,
+    ) -> (Transfers<'static, Message, Theme, Recorder>, Node) {
+        let mut board = self.view();
+        self.tree.diff(&board as &dyn Widget<_, _, _>);
+        let node = board.layout(
+            &mut self.tree,
+            &Recorder::default(),
+            &Limits::new(Size::ZERO, Size::INFINITE),
+        );
+        (board, node)
+    }
+
+    /// Handles the event with the cursor at the given position, returning the published
+    /// messages.
+    fn update(&mut self, event: Event, cursor: Point) -> Vec<Message> {
+        let (mut board, node) = self.build();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        board.update(
+            &mut self.tree,
+            &event,
+            Layout::new(&node),
+            Cursor::Available(cursor),
+            &Recorder::default(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        drop(shell);
+        messages
+    }
+
+    /// Returns the offsets the items of the list at the given index are drawn at.
+    fn offsets(&mut self, list: usize) -> Vec<Vector> {
+        let (board, node) = self.build();
+        let mut recorder = Recorder::default();
+
+        board.draw(
+            &self.tree,
+            &mut recorder,
+            &Theme::Light,
+            &Style::default(),
+            Layout::new(&node),
+            Cursor::Unavailable,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+        recorder.translations[list * 3..list * 3 + 3].to_vec()
+    }
+}
+
+/// Where the second slot of the first list of a [`Board`] is.
+const SECOND_SLOT: Point = Point::new(50.0, 30.0);
+
+/// Picks up the first item of the second list of the board, and drags it to the second slot of
+/// the first list.
+fn drag_to_second_slot(board: &mut Board) {
+    let start = Point::new(50.0, 110.0);
+
+    assert!(board.update(press(), start).is_empty());
+    assert!(board.update(move_to(SECOND_SLOT), SECOND_SLOT).is_empty());
+    assert!(board.update(redraw(), SECOND_SLOT).is_empty());
+}
+
+#[test]
+fn dropped_items_are_reordered() {
+    let mut list = List::new("list", Point::ORIGIN);
+    let start = Point::new(50.0, 10.0);
+    let end = Point::new(50.0, 58.0);
+
+    list.update(press(), start);
+    list.update(move_to(end), end);
+    list.update(redraw(), end);
+
+    // the second and third items moved up, and the hidden item moved to the end
+    assert_eq!(
+        list.offsets(),
+        [
+            Vector::new(0.0, 40.0),
+            Vector::new(0.0, -20.0),
+            Vector::new(0.0, -20.0)
+        ]
+    );
+    assert_eq!(list.update(release(), end), [Message::Reorder(0, 2)]);
+}
+
+#[test]
+fn items_land_where_the_cursor_is() {
+    let mut list = List::laid_out(
+        items(&[
+            Size::new(100.0, 100.0),
+            Size::new(100.0, 20.0),
+            Size::new(100.0, 20.0),
+        ]),
+        Point::ORIGIN,
+        Size::INFINITE,
+    );
+    // grabbed near its bottom edge, the center of the item stays above the second item
+    let start = Point::new(50.0, 95.0);
+    let end = Point::new(50.0, 125.0);
+
+    list.update(press(), start);
+    list.update(move_to(end), end);
+
+    assert_eq!(list.update(release(), end), [Message::Reorder(0, 1)]);
+}
+
+#[test]
+fn grids_move_their_items_where_they_wrap_in_the_layout() {
+    // the grid wraps at 250, but shrinks to the 220 of its first row
+    let mut list = List::laid_out(
+        items(&[
+            Size::new(100.0, 20.0),
+            Size::new(120.0, 20.0),
+            Size::new(140.0, 20.0),
+        ])
+        .direction(Direction::Grid)
+        .width(Length::Shrink),
+        Point::ORIGIN,
+        Size::new(250.0, f32::INFINITY),
+    );
+    let start = Point::new(70.0, 30.0);
+    let end = Point::new(10.0, 10.0);
+
+    list.update(press(), start);
+    list.update(move_to(end), end);
+    list.update(redraw(), end);
+
+    // the last item moves to the start, and still fits next to the first one
+    assert_eq!(
+        list.offsets(),
+        [
+            Vector::new(140.0, 0.0),
+            Vector::new(-100.0, 20.0),
+            Vector::new(0.0, -20.0)
+        ]
+    );
+    assert_eq!(list.update(release(), end), [Message::Reorder(2, 0)]);
+}
+
+#[test]
+fn cancelled_drags_publish_nothing() {
+    let mut list = List::new("list", Point::ORIGIN);
+    let start = Point::new(50.0, 10.0);
+    let end = Point::new(50.0, 58.0);
+
+    list.update(press(), start);
+    list.update(move_to(end), end);
+    list.update(
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+        end,
+    );
+    list.update(redraw(), end);
+
+    assert!(list.update(release(), end).is_empty());
+    assert_eq!(list.offsets(), [Vector::ZERO; 3]);
+}
+
+#[test]
+fn items_dropped_outside_of_the_lists_are_reported() {
+    let mut list = List::new("list", Point::ORIGIN);
+    let start = Point::new(50.0, 10.0);
+    let end = Point::new(50.0, 300.0);
+
+    list.update(press(), start);
+    list.update(move_to(end), end);
+
+    assert_eq!(list.update(release(), end), [Message::DropOutside(0)]);
+}
+
+#[test]
+fn items_dropped_on_another_list_are_transferred() {
+    let mut board = Board::new(&[("to", "items"), ("from", "items")]);
+
+    drag_to_second_slot(&mut board);
+    assert_eq!(
+        board.offsets(0),
+        [Vector::ZERO, Vector::new(0.0, 20.0), Vector::new(0.0, 20.0)]
+    );
+
+    assert_eq!(
+        board.update(release(), SECOND_SLOT),
+        [Message::Transfer(Transfer {
+            from_list: Id::new("from"),
+            from_index: 0,
+            to_list: Id::new("to"),
+            to_index: 1,
+        })]
+    );
+
+    board.update(redraw(), SECOND_SLOT);
+    assert_eq!(board.offsets(0), [Vector::ZERO; 3]);
+}
+
+#[test]
+fn lists_of_other_groups_reject_items() {
+    let mut board = Board::new(&[("to", "cards"), ("from", "items")]);
+
+    drag_to_second_slot(&mut board);
+    assert_eq!(board.offsets(0), [Vector::ZERO; 3]);
+
+    // the item isn't over a list of its group
+    assert_eq!(
+        board.update(release(), SECOND_SLOT),
+        [Message::DropOutside(0)]
+    );
+}
+
+#[test]
+fn removing_the_source_list_closes_the_gap() {
+    let mut board = Board::new(&[("to", "items"), ("from", "items")]);
+
+    drag_to_second_slot(&mut board);
+    assert_ne!(board.offsets(0), [Vector::ZERO; 3]);
+
+    // the application removed the list while its item was dragged
+    board.lists.pop();
+
+    // the transfers notice on the next event, and the list follows on the one after
+    assert!(board.update(redraw(), SECOND_SLOT).is_empty());
+    assert!(board.update(redraw(), SECOND_SLOT).is_empty());
+    assert_eq!(board.offsets(0), [Vector::ZERO; 3]);
+}
+
+#[test]
+fn items_dropped_on_the_widgets_inside_are_kept() {
+    let start = Point::new(50.0, 10.0);
+    let adder = Point::new(50.0, 130.0);
+    let mut board = Board::new(&[("list", "items")]);
+
+    board.update(press(), start);
+    board.update(move_to(adder), adder);
+    assert_eq!(board.update(release(), adder), [Message::DropOutside(0)]);
+
+    // dropped on the adder, the item is moved to the end of the list like below it
+    board.inside = vec![Id::new("adder")];
+    board.update(press(), start);
+    board.update(move_to(adder), adder);
+    assert_eq!(board.update(release(), adder), [Message::Reorder(0, 2)]);
+}